@@ -0,0 +1,326 @@
+//! The buffer behind [`Ts::to_rust_borrowed`][crate::Ts::to_rust_borrowed].
+//!
+//! A type using the `json` backend is deserialized from JSON text kept in the buffer. One using
+//! the `js` backend is deserialized from the JS value as `to_rust` would, except that each string
+//! or byte array is moved into the buffer first and handed to the visitor as borrowed.
+
+#[cfg(feature = "js")]
+use std::cell::RefCell;
+#[cfg(feature = "js")]
+use std::fmt;
+
+#[cfg(feature = "js")]
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+
+/// Holds what [`Ts::to_rust_borrowed`] deserializes from, so that the result can borrow its
+/// strings instead of copying them. It can be reused once the result is dropped.
+///
+/// [`Ts::to_rust_borrowed`]: crate::Ts::to_rust_borrowed
+#[derive(Debug, Default)]
+pub struct BorrowBuffer {
+    #[cfg(feature = "json")]
+    pub(crate) json: String,
+    #[cfg(feature = "js")]
+    pub(crate) arena: Arena,
+}
+
+impl BorrowBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The strings and byte arrays read from a JS value. Entries are only added while the buffer is
+/// borrowed, and dropped through `&mut`, so their contents stay where they are for as long as
+/// anything borrows them.
+#[cfg(feature = "js")]
+#[derive(Debug, Default)]
+pub(crate) struct Arena {
+    strings: RefCell<Vec<String>>,
+    bytes: RefCell<Vec<Vec<u8>>>,
+}
+
+#[cfg(feature = "js")]
+impl Arena {
+    pub(crate) fn clear(&mut self) {
+        self.strings.get_mut().clear();
+        self.bytes.get_mut().clear();
+    }
+
+    fn keep_str(&self, string: String) -> &str {
+        let kept: *const str = string.as_str();
+        self.strings.borrow_mut().push(string);
+        // SAFETY: moving a `String` into the vector leaves its heap buffer in place, and the
+        // entry is only dropped by `clear`, which needs `&mut self` and so outlives the result.
+        unsafe { &*kept }
+    }
+
+    fn keep_bytes(&self, bytes: Vec<u8>) -> &[u8] {
+        let kept: *const [u8] = bytes.as_slice();
+        self.bytes.borrow_mut().push(bytes);
+        // SAFETY: as in `keep_str`.
+        unsafe { &*kept }
+    }
+}
+
+/// Wraps a deserializer, a visitor, a seed or one of the accesses of a `js` backend
+/// deserializer so that the strings and bytes it yields are borrowed from the arena.
+#[cfg(feature = "js")]
+pub(crate) struct Borrowing<'a, X> {
+    pub(crate) inner: X,
+    pub(crate) arena: &'a Arena,
+}
+
+#[cfg(feature = "js")]
+impl<'a, X> Borrowing<'a, X> {
+    fn wrap<Y>(&self, inner: Y) -> Borrowing<'a, Y> {
+        Borrowing {
+            inner,
+            arena: self.arena,
+        }
+    }
+}
+
+#[cfg(feature = "js")]
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V: Visitor<'a>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                let visitor = self.wrap(visitor);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "js")]
+impl<'a, D: Deserializer<'a>> Deserializer<'a> for Borrowing<'a, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+        deserialize_ignored_any()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+#[cfg(feature = "js")]
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "js")]
+impl<'a, V: Visitor<'a>> Visitor<'a> for Borrowing<'a, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool)
+        visit_i8(i8)
+        visit_i16(i16)
+        visit_i32(i32)
+        visit_i64(i64)
+        visit_i128(i128)
+        visit_u8(u8)
+        visit_u16(u16)
+        visit_u32(u32)
+        visit_u64(u64)
+        visit_u128(u128)
+        visit_f32(f32)
+        visit_f64(f64)
+        visit_char(char)
+        visit_borrowed_str(&'a str)
+        visit_borrowed_bytes(&'a [u8])
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.visit_string(v.to_string())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        self.inner.visit_borrowed_str(self.arena.keep_str(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.visit_byte_buf(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.inner.visit_borrowed_bytes(self.arena.keep_bytes(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'a>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'a>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'a>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = self.wrap(seq);
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: de::MapAccess<'a>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let map = self.wrap(map);
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'a>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let data = self.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+#[cfg(feature = "js")]
+impl<'a, T: DeserializeSeed<'a>> DeserializeSeed<'a> for Borrowing<'a, T> {
+    type Value = T::Value;
+
+    fn deserialize<D: Deserializer<'a>>(self, deserializer: D) -> Result<T::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+#[cfg(feature = "js")]
+impl<'a, A: de::SeqAccess<'a>> de::SeqAccess<'a> for Borrowing<'a, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "js")]
+impl<'a, A: de::MapAccess<'a>> de::MapAccess<'a> for Borrowing<'a, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'a>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "js")]
+impl<'a, A: de::EnumAccess<'a>> de::EnumAccess<'a> for Borrowing<'a, A> {
+    type Error = A::Error;
+    type Variant = Borrowing<'a, A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'a>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), A::Error> {
+        let arena = self.arena;
+        let (value, variant) = self.inner.variant_seed(Borrowing { inner: seed, arena })?;
+        Ok((
+            value,
+            Borrowing {
+                inner: variant,
+                arena,
+            },
+        ))
+    }
+}
+
+#[cfg(feature = "js")]
+impl<'a, A: de::VariantAccess<'a>> de::VariantAccess<'a> for Borrowing<'a, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'a>>(self, seed: T) -> Result<T::Value, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: Visitor<'a>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'a>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
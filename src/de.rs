@@ -0,0 +1,102 @@
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsValue;
+
+#[cfg(feature = "js")]
+use crate::borrow::Borrowing;
+use crate::{Backend, BorrowBuffer, Error, SerializationError, Tsify};
+
+/// Converts `js` into `T` like [`Tsify::from_js`], but keeps track of where in `js`
+/// deserialization failed so that [`Error::path`] can point at it.
+pub(crate) fn from_js<T: Tsify + DeserializeOwned>(js: JsValue) -> Result<T, Error> {
+    deserialize(T::SERIALIZATION_CONFIG.backend, js).map_err(|(path, inner)| Error {
+        path,
+        ..Error::deserialization::<T>(inner)
+    })
+}
+
+/// Converts `js` into `T` with `backend`, returning the path to the failure with the error.
+pub(crate) fn deserialize<T: DeserializeOwned>(
+    backend: Backend,
+    js: JsValue,
+) -> Result<T, (String, SerializationError)> {
+    match backend {
+        #[cfg(feature = "json")]
+        Backend::Json => deserialize_json(js),
+        #[cfg(feature = "js")]
+        Backend::Js => deserialize_js(js),
+    }
+}
+
+#[cfg(feature = "js")]
+fn deserialize_js<T: DeserializeOwned>(js: JsValue) -> Result<T, (String, SerializationError)> {
+    let deserializer = serde_wasm_bindgen::Deserializer::from(js);
+    serde_path_to_error::deserialize(deserializer).map_err(split_path)
+}
+
+#[cfg(feature = "json")]
+fn deserialize_json<T: DeserializeOwned>(js: JsValue) -> Result<T, (String, SerializationError)> {
+    deserialize_str(&stringify(&js)?)
+}
+
+/// Converts `js` into `U` with the backend of `T`, keeping what `U` borrows in `buffer`.
+pub(crate) fn from_js_borrowed<'a, T: Tsify + ?Sized, U: serde::Deserialize<'a>>(
+    js: &JsValue,
+    buffer: &'a mut BorrowBuffer,
+) -> Result<U, Error> {
+    let result = match T::SERIALIZATION_CONFIG.backend {
+        #[cfg(feature = "json")]
+        Backend::Json => stringify(js).and_then(|json| {
+            buffer.json = json;
+            deserialize_str(&buffer.json)
+        }),
+        #[cfg(feature = "js")]
+        Backend::Js => {
+            buffer.arena.clear();
+            let deserializer = Borrowing {
+                inner: serde_wasm_bindgen::Deserializer::from(js.clone()),
+                arena: &buffer.arena,
+            };
+            serde_path_to_error::deserialize(deserializer).map_err(split_path)
+        }
+    };
+
+    result.map_err(|(path, inner)| Error {
+        path,
+        ..Error::deserialization::<T>(inner)
+    })
+}
+
+/// The same conversion as `gloo_utils::format::JsValueSerdeExt::into_serde`.
+#[cfg(feature = "json")]
+fn stringify(js: &JsValue) -> Result<String, (String, SerializationError)> {
+    if js.is_undefined() {
+        return Ok(String::new());
+    }
+
+    js_sys::JSON::stringify(js)
+        .map(String::from)
+        .map_err(|exception| (String::new(), crate::error::exception_to_error(exception)))
+}
+
+#[cfg(feature = "json")]
+fn deserialize_str<'a, T: serde::Deserialize<'a>>(
+    json: &'a str,
+) -> Result<T, (String, SerializationError)> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(split_path)?;
+    deserializer
+        .end()
+        .map_err(|inner| (String::new(), inner.into()))?;
+    Ok(value)
+}
+
+/// Formats the path like `items[3].price`, leaving it empty for the value itself.
+fn split_path<E: Into<SerializationError>>(
+    error: serde_path_to_error::Error<E>,
+) -> (String, SerializationError) {
+    let path = match error.path().iter().next() {
+        Some(_) => error.path().to_string(),
+        None => String::new(),
+    };
+    (path, error.into_inner().into())
+}
@@ -0,0 +1,46 @@
+//! The characters of [`Tsify::describe_typescript_type`], informed to wasm-bindgen one at a
+//! time. Off wasm there is no wasm-bindgen to inform, so [`typescript_type`] collects them
+//! instead, which lets host tests read the Typescript type an impl describes.
+
+use crate::Tsify;
+
+/// Informs wasm-bindgen of one character of a Typescript type.
+#[cfg(target_arch = "wasm32")]
+#[inline(always)]
+pub fn inform(c: u32) {
+    wasm_bindgen::describe::inform(c);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static INFORMED: std::cell::RefCell<Option<Vec<u32>>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Informs wasm-bindgen of one character of a Typescript type, or records it for
+/// [`typescript_type`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn inform(c: u32) {
+    let recorded = INFORMED.with(|informed| match informed.borrow_mut().as_mut() {
+        Some(informed) => {
+            informed.push(c);
+            true
+        }
+        None => false,
+    });
+
+    if !recorded {
+        wasm_bindgen::describe::inform(c);
+    }
+}
+
+/// The Typescript type that `T` informs wasm-bindgen of.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn typescript_type<T: Tsify>() -> String {
+    let outer = INFORMED.with(|informed| informed.replace(Some(Vec::new())));
+    T::describe_typescript_type();
+    let chars = INFORMED
+        .with(|informed| informed.replace(outer))
+        .unwrap_or_default();
+
+    chars.into_iter().filter_map(char::from_u32).collect()
+}
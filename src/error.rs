@@ -1,39 +1,202 @@
 use std::fmt;
 
+use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(not(feature = "js"))]
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+
 /// An error type wrapping serialization or deserialization errors from either `serde_json` or
-/// `serde_wasm_bindgen`, depending on whether the `json` or `js` feature is enabled.
+/// `serde_wasm_bindgen`, depending on the [`Backend`][crate::Backend] of the type.
 ///
 #[derive(Debug)]
 pub struct Error {
     // All private internals
     //
-    /// XXX: May want to put type-name printing behind an off-by-default feature flag,
-    /// as it does add bloat that some users might not want.
-    pub(crate) type_name: &'static str,
-    /// true -> During deserialization; false -> during serialization
-    pub(crate) de: bool,
+    /// `None` with the `omit-type-name` feature, which keeps the names out of the binary.
+    pub(crate) type_name: Option<&'static str>,
+    pub(crate) direction: Direction,
+    /// Where in the value deserialization failed, e.g. `items[3].price`. Empty if it failed on
+    /// the value itself, or for any other direction.
+    pub(crate) path: String,
     pub(crate) inner: SerializationError,
 }
 
-#[cfg(all(feature = "json", not(feature = "js")))]
-type SerializationError = serde_json::Error;
+impl Error {
+    /// An error converting a `T` into a `JsValue`, for manual [`Tsify`][crate::Tsify]
+    /// implementations.
+    pub fn serialization<T: ?Sized>(inner: impl Into<SerializationError>) -> Self {
+        Self::new::<T>(Direction::Serialize, inner.into())
+    }
+
+    /// An error converting a `JsValue` into a `T`, for manual [`Tsify`][crate::Tsify]
+    /// implementations.
+    pub fn deserialization<T: ?Sized>(inner: impl Into<SerializationError>) -> Self {
+        Self::new::<T>(Direction::Deserialize, inner.into())
+    }
+
+    #[cfg_attr(
+        feature = "omit-type-name",
+        allow(clippy::extra_unused_type_parameters)
+    )]
+    pub(crate) fn new<T: ?Sized>(direction: Direction, inner: SerializationError) -> Self {
+        #[cfg(not(feature = "omit-type-name"))]
+        let type_name = Some(std::any::type_name::<T>());
+        #[cfg(feature = "omit-type-name")]
+        let type_name = None;
+
+        Self {
+            type_name,
+            direction,
+            path: String::new(),
+            inner,
+        }
+    }
+
+    /// The Rust type that failed to convert, or of the [`TsFn`][crate::TsFn] or `TsPromise`
+    /// that failed. `None` with the `omit-type-name` feature.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// Whether converting a Rust value into a `JsValue` failed.
+    pub fn is_serialize(&self) -> bool {
+        self.direction == Direction::Serialize
+    }
+
+    /// Whether converting a `JsValue` into a Rust value failed.
+    pub fn is_deserialize(&self) -> bool {
+        self.direction == Direction::Deserialize
+    }
+
+    /// Whether a JS function called through [`TsFn::call`][crate::TsFn::call] threw.
+    pub fn is_call(&self) -> bool {
+        self.direction == Direction::Call
+    }
+
+    /// Whether a JS promise awaited through `TsPromise::to_rust` was rejected.
+    pub fn is_reject(&self) -> bool {
+        self.direction == Direction::Reject
+    }
+
+    /// The path to the part of the value that failed to deserialize, e.g. `items[3].price`.
+    ///
+    /// Empty if the value itself could not be deserialized, or if the error did not occur while
+    /// deserializing.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The underlying `serde_json` or `serde_wasm_bindgen` error. For [`Error::is_call`] and
+    /// [`Error::is_reject`], this wraps what was thrown or the rejection reason.
+    pub fn inner(&self) -> &SerializationError {
+        &self.inner
+    }
+
+    /// Returns the underlying `serde_json` or `serde_wasm_bindgen` error.
+    pub fn into_inner(self) -> SerializationError {
+        self.inner
+    }
+}
+
+/// What tsify was doing when the error occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Direction {
+    /// Converting a Rust value into a `JsValue`.
+    Serialize,
+    /// Converting a `JsValue` into a Rust value.
+    Deserialize,
+    /// Calling a JS function, which threw the `inner` error.
+    Call,
+    /// Awaiting a JS promise, which was rejected with the `inner` error.
+    Reject,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Serialize => "serialize",
+            Direction::Deserialize => "deserialize",
+            Direction::Call => "call",
+            Direction::Reject => "reject",
+        }
+    }
+}
+
+/// The error of the serializer that failed, see [`Backend`][crate::Backend].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SerializationError {
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    #[cfg(feature = "js")]
+    Js(serde_wasm_bindgen::Error),
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for SerializationError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
 #[cfg(feature = "js")]
-type SerializationError = serde_wasm_bindgen::Error;
+impl From<serde_wasm_bindgen::Error> for SerializationError {
+    fn from(error: serde_wasm_bindgen::Error) -> Self {
+        Self::Js(error)
+    }
+}
+
+impl fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json(error) => error.fmt(f),
+            #[cfg(feature = "js")]
+            Self::Js(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "json")]
+            Self::Json(error) => error.source(),
+            #[cfg(feature = "js")]
+            Self::Js(error) => error.source(),
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.de {
-            write!(
+        let name = |prefix: &str| match self.type_name {
+            Some(type_name) => format!("{prefix}`{type_name}`"),
+            None => String::new(),
+        };
+
+        match self.direction {
+            Direction::Deserialize if !self.path.is_empty() => write!(
                 f,
-                "Failed to deserialize JsValue into type `{}`: {}",
-                self.type_name, self.inner
-            )
-        } else {
-            write!(
+                "Failed to deserialize JsValue{} at `{}`: {}",
+                name(" into type "),
+                self.path,
+                self.inner
+            ),
+            Direction::Deserialize => write!(
                 f,
-                "Failed to serialize type `{}` into JsValue: {}",
-                self.type_name, self.inner
-            )
+                "Failed to deserialize JsValue{}: {}",
+                name(" into type "),
+                self.inner
+            ),
+            Direction::Serialize => write!(
+                f,
+                "Failed to serialize{} into JsValue: {}",
+                name(" type "),
+                self.inner
+            ),
+            Direction::Call => write!(f, "Calling JS function{} threw: {}", name(" "), self.inner),
+            Direction::Reject => write!(f, "JS promise{} was rejected: {}", name(" "), self.inner),
         }
     }
 }
@@ -45,3 +208,105 @@ impl std::error::Error for Error {
         Some(&self.inner)
     }
 }
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TSIFY_ERROR: &'static str = r#"
+declare global {
+    /**
+     * Thrown by functions returning `Result<_, JsValue>` when tsify fails to convert a value,
+     * call a callback or await a promise.
+     */
+    class TsifyError extends Error {
+        name: "TsifyError";
+        /** The Rust type being converted, or of the callback or promise. Omitted with the `omit-type-name` feature. */
+        typeName?: string;
+        direction: "serialize" | "deserialize" | "call" | "reject";
+        /** Where in the value deserialization failed, e.g. `items[3].price`, or `""`. */
+        path: string;
+        /** The underlying serde error, or what the callback threw or the promise was rejected with. */
+        cause: unknown;
+    }
+}
+"#;
+
+// The class is put on `globalThis`, as wasm-bindgen has no way to export it from the module.
+// Every module using tsify then throws the same class, so `instanceof` works across them.
+#[wasm_bindgen(inline_js = r#"
+globalThis.TsifyError ??= class TsifyError extends Error {
+    constructor(message, typeName, direction, path, cause) {
+        super(message, { cause });
+        this.name = "TsifyError";
+        if (typeName !== undefined) {
+            this.typeName = typeName;
+        }
+        this.direction = direction;
+        this.path = path;
+    }
+};
+
+export const TsifyError = globalThis.TsifyError;
+"#)]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Error)]
+    type TsifyError;
+
+    #[wasm_bindgen(constructor)]
+    fn new(
+        message: &str,
+        type_name: Option<&str>,
+        direction: &str,
+        path: &str,
+        cause: &JsValue,
+    ) -> TsifyError;
+}
+
+/// Converts the error into an instance of the JS class `TsifyError`, an `Error` subclass with
+/// `typeName`, `direction`, `path` and `cause` properties. It is declared in Typescript as a
+/// global class, so that `e instanceof TsifyError` needs no import.
+///
+/// [`JsError`][wasm_bindgen::JsError] converts any [`std::error::Error`] into a plain `Error`
+/// with only the message, so return `Result<_, JsValue>` to throw this one instead.
+impl From<Error> for JsValue {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+        TsifyError::new(
+            &message,
+            error.type_name,
+            error.direction.as_str(),
+            &error.path,
+            &error_to_js(error.inner),
+        )
+        .into()
+    }
+}
+
+/// The inverse of [`exception_to_error`], as far as a `serde_json` error can tell.
+fn error_to_js(error: SerializationError) -> JsValue {
+    match error {
+        #[cfg(feature = "json")]
+        SerializationError::Json(error) => js_sys::Error::new(&error.to_string()).into(),
+        #[cfg(feature = "js")]
+        SerializationError::Js(error) => error.into(),
+    }
+}
+
+/// Wrap an exception thrown by JS, or the reason a promise was rejected, as a
+/// [`SerializationError`]. With the `js` feature the value is kept as is; otherwise only its
+/// message survives.
+pub(crate) fn exception_to_error(exception: JsValue) -> SerializationError {
+    #[cfg(feature = "js")]
+    {
+        SerializationError::Js(exception.into())
+    }
+
+    #[cfg(not(feature = "js"))]
+    {
+        let message = match exception.dyn_ref::<js_sys::Error>() {
+            Some(error) => String::from(error.message()),
+            None => exception
+                .as_string()
+                .unwrap_or_else(|| format!("{exception:?}")),
+        };
+        SerializationError::Json(serde::de::Error::custom(message))
+    }
+}
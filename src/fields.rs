@@ -0,0 +1,129 @@
+//! Support for the getters, setters and patches that `#[tsify(getters)]`, `#[tsify(setters)]`
+//! and `#[tsify(patch)]` generate, which read or write a single property of a
+//! [`Ts<T>`][crate::Ts] without converting the rest of it.
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{Backend, Error, Mismatch, SerializationConfig, Ts, Tsify};
+
+/// The JS value of `ts`, whose properties the generated methods read and write.
+pub fn js<T: Tsify, C>(ts: &Ts<T, C>) -> &JsValue {
+    ts.as_js()
+}
+
+/// The value of the first of `keys` (a field's name, then its aliases) that `js` has, or
+/// `undefined` if it has none of them.
+pub fn property(js: &JsValue, keys: &[&str]) -> JsValue {
+    keys.iter()
+        .filter_map(|key| js_sys::Reflect::get(js, &JsValue::from_str(key)).ok())
+        .find(|value| !value.is_undefined())
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Deserializes the field `keys` of the `T` in `js` as `U`, with the backend of `T`. A failure is
+/// reported as one to deserialize `T`, at the path of the field, like [`Ts::to_rust`] would.
+///
+/// [`Ts::to_rust`]: crate::Ts::to_rust
+pub fn get<T: Tsify, U: DeserializeOwned>(
+    js: &JsValue,
+    keys: &[&str],
+    default: Option<fn() -> U>,
+) -> Result<U, Error> {
+    let backend = T::SERIALIZATION_CONFIG.backend;
+    let key = keys[0];
+    let value = property(js, keys);
+
+    if value.is_undefined() {
+        if let Some(default) = default {
+            return Ok(default());
+        }
+
+        // Only an `Option` may be missing, and JSON has no `undefined` to read it from.
+        let missing = match backend {
+            #[cfg(feature = "json")]
+            Backend::Json => JsValue::NULL,
+            #[cfg(feature = "js")]
+            Backend::Js => JsValue::UNDEFINED,
+        };
+        return crate::de::deserialize(backend, missing).map_err(|_| {
+            Mismatch::custom(format_args!("missing field `{key}`")).into_error::<T>()
+        });
+    }
+
+    crate::de::deserialize(backend, value).map_err(|(path, inner)| {
+        let path = match path.chars().next() {
+            None => key.to_string(),
+            Some('[') => format!("{key}{path}"),
+            Some(_) => format!("{key}.{path}"),
+        };
+        Error {
+            path,
+            ..Error::deserialization::<T>(inner)
+        }
+    })
+}
+
+/// Serializes `value` with `config` into the property `key` of the `T` in `js`, leaving the
+/// other properties and the object itself as they are. A failure, including a property that
+/// cannot be written, e.g. on a frozen object, is reported as one to serialize `T`, at the path
+/// of the field.
+pub fn set<T: Tsify + ?Sized, U: Serialize + ?Sized>(
+    js: &JsValue,
+    key: &str,
+    value: &U,
+    config: &SerializationConfig,
+) -> Result<(), Error> {
+    let error = |inner| Error {
+        path: key.to_string(),
+        ..Error::serialization::<T>(inner)
+    };
+
+    let value = crate::ser::serialize(value, config).map_err(error)?;
+    let written = js_sys::Reflect::set(js, &JsValue::from_str(key), &value)
+        .map_err(|exception| error(crate::error::exception_to_error(exception)))?;
+    if !written {
+        return Err(error(read_only(format_args!(
+            "cannot assign to read only property `{key}`"
+        ))));
+    }
+    Ok(())
+}
+
+/// Deletes the property `key` of the `T` in `js`, for a value its field skips serializing. A
+/// property that cannot be deleted is reported like [`set`] reports one that cannot be written.
+pub fn remove<T: Tsify + ?Sized>(js: &JsValue, key: &str) -> Result<(), Error> {
+    let error = |inner| Error {
+        path: key.to_string(),
+        ..Error::serialization::<T>(inner)
+    };
+
+    let deleted = js_sys::Reflect::delete_property(
+        js.unchecked_ref::<js_sys::Object>(),
+        &JsValue::from_str(key),
+    )
+    .map_err(|exception| error(crate::error::exception_to_error(exception)))?;
+    if !deleted {
+        return Err(error(read_only(format_args!(
+            "cannot delete property `{key}`"
+        ))));
+    }
+    Ok(())
+}
+
+/// The `TypeError` that JS in strict mode throws where `Reflect` returns `false`.
+fn read_only(message: std::fmt::Arguments) -> crate::SerializationError {
+    let exception = js_sys::TypeError::new(&message.to_string());
+    crate::error::exception_to_error(exception.into())
+}
+
+/// Deserializes a patch field for an optional field, reading a present `null` (or `undefined`)
+/// as `Some(None)`, which clears the field, where serde alone would read it as `None`, which
+/// leaves it as it is.
+pub fn double_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
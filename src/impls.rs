@@ -0,0 +1,242 @@
+//! [`Tsify`] for standard library types, so that e.g. a whole `Ts<Vec<Foo>>` crosses the ABI as
+//! a single `Foo[]` rather than one JS handle per element.
+//!
+//! Their Typescript types follow what the derive generates for fields of the same type. The
+//! serialization config is taken from the element types, so a `Vec<Foo>` is serialized the way
+//! `Foo` asks to be. The parts of a tuple or map are serialized with one config, so those whose
+//! type depends on it have to agree on it, which is checked when the impl is instantiated.
+//!
+//! wasm-bindgen only interprets straight-line `describe` code, so every name below is informed
+//! one character at a time, and branches are on `const` blocks that are resolved when the impl
+//! is instantiated.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{describe::inform, JsTypeOf, SerializationConfig, Tsify};
+
+macro_rules! impl_tsify_keyword {
+    ($chars:tt, $ignores_config:literal => $($ty:ty),*) => {$(
+        impl Tsify for $ty {
+            type JsType = JsTypeOf<Self>;
+            const DECL: &'static str = "";
+            const TYPESCRIPT_TYPE_IGNORES_CONFIG: bool = $ignores_config;
+            const TYPESCRIPT_TYPE_LEN: u32 = $chars.len() as u32;
+
+            fn describe_typescript_type() {
+                inform_chars!$chars;
+            }
+        }
+    )*};
+}
+
+impl_tsify_keyword!(['b', 'o', 'o', 'l', 'e', 'a', 'n'], true => bool);
+impl_tsify_keyword!(['s', 't', 'r', 'i', 'n', 'g'], true => char, String);
+impl_tsify_keyword!(
+    ['n', 'u', 'm', 'b', 'e', 'r'], true =>
+    u8, u16, u32, i8, i16, i32, f32, f64
+);
+// serde-wasm-bindgen converts these to a `BigInt` under `large_number_types_as_bigints`.
+impl_tsify_keyword!(['n', 'u', 'm', 'b', 'e', 'r'], false => u64, usize, i64, isize);
+
+// serde-wasm-bindgen always converts 128-bit integers to a `BigInt`. A tuple or map that would
+// serialize one with another backend than its own is rejected by `merge_configs`.
+macro_rules! impl_tsify_128 {
+    ($($ty:ty),*) => {$(
+        impl Tsify for $ty {
+            type JsType = JsTypeOf<Self>;
+            const DECL: &'static str = "";
+            // `bigint` or `number`.
+            const TYPESCRIPT_TYPE_LEN: u32 = 6;
+
+            fn describe_typescript_type() {
+                if const { Self::SERIALIZATION_CONFIG.backend.is_js() } {
+                    inform_chars!('b', 'i', 'g', 'i', 'n', 't');
+                } else {
+                    inform_chars!('n', 'u', 'm', 'b', 'e', 'r');
+                }
+            }
+        }
+    )*};
+}
+
+impl_tsify_128!(u128, i128);
+
+impl<T: Tsify> Tsify for Option<T> {
+    type JsType = JsTypeOf<Self>;
+    const DECL: &'static str = "";
+    const SERIALIZATION_CONFIG: SerializationConfig = T::SERIALIZATION_CONFIG;
+    const TYPESCRIPT_TYPE_IS_UNION: bool = true;
+    // ` | undefined` or ` | null`.
+    const TYPESCRIPT_TYPE_LEN: u32 =
+        T::TYPESCRIPT_TYPE_LEN + if none_is_undefined::<T>() { 12 } else { 7 };
+
+    fn describe_typescript_type() {
+        T::describe_typescript_type();
+        inform_chars!(' ', '|', ' ');
+        if const { none_is_undefined::<T>() } {
+            inform_chars!('u', 'n', 'd', 'e', 'f', 'i', 'n', 'e', 'd');
+        } else {
+            inform_chars!('n', 'u', 'l', 'l');
+        }
+    }
+}
+
+const fn none_is_undefined<T: Tsify>() -> bool {
+    T::SERIALIZATION_CONFIG.backend.is_js() && !T::SERIALIZATION_CONFIG.missing_as_null
+}
+
+macro_rules! impl_tsify_array {
+    ($($ty:ty),*) => {$(
+        impl<T: Tsify> Tsify for $ty {
+            type JsType = JsTypeOf<Self>;
+            const DECL: &'static str = "";
+            const SERIALIZATION_CONFIG: SerializationConfig = T::SERIALIZATION_CONFIG;
+            const TYPESCRIPT_TYPE_IGNORES_CONFIG: bool = T::TYPESCRIPT_TYPE_IGNORES_CONFIG;
+            // `[]`, and parentheses around a union.
+            const TYPESCRIPT_TYPE_LEN: u32 =
+                T::TYPESCRIPT_TYPE_LEN + 2 + if T::TYPESCRIPT_TYPE_IS_UNION { 2 } else { 0 };
+
+            fn describe_typescript_type() {
+                if const { T::TYPESCRIPT_TYPE_IS_UNION } {
+                    inform('(' as u32);
+                    T::describe_typescript_type();
+                    inform(')' as u32);
+                } else {
+                    T::describe_typescript_type();
+                }
+                inform_chars!('[', ']');
+            }
+        }
+    )*};
+}
+
+impl_tsify_array!(Vec<T>, Box<[T]>);
+
+macro_rules! impl_tsify_map {
+    ($([$($s:ident)?] $ty:ty),*) => {$(
+        impl<K: Tsify, V: Tsify $(, $s)?> Tsify for $ty {
+            type JsType = JsTypeOf<Self>;
+            const DECL: &'static str = "";
+            const SERIALIZATION_CONFIG: SerializationConfig =
+                merge_configs(&[part::<K>(), part::<V>()]);
+            // `Map<`/`Record<`, `, ` and `>`.
+            const TYPESCRIPT_TYPE_LEN: u32 = if map_is_record::<Self>() { 7 } else { 4 }
+                + K::TYPESCRIPT_TYPE_LEN
+                + 2
+                + V::TYPESCRIPT_TYPE_LEN
+                + 1;
+
+            fn describe_typescript_type() {
+                if const { map_is_record::<Self>() } {
+                    inform_chars!('R', 'e', 'c', 'o', 'r', 'd', '<');
+                } else {
+                    inform_chars!('M', 'a', 'p', '<');
+                }
+                K::describe_typescript_type();
+                inform_chars!(',', ' ');
+                V::describe_typescript_type();
+                inform('>' as u32);
+            }
+        }
+    )*};
+}
+
+impl_tsify_map!([S] HashMap<K, V, S>, [] BTreeMap<K, V>);
+
+const fn map_is_record<T: Tsify>() -> bool {
+    !T::SERIALIZATION_CONFIG.backend.is_js() || T::SERIALIZATION_CONFIG.hashmap_as_object
+}
+
+/// The config of `T` as a part of a tuple or map, and whether its type ignores the config.
+const fn part<T: Tsify>() -> (SerializationConfig, bool) {
+    (T::SERIALIZATION_CONFIG, T::TYPESCRIPT_TYPE_IGNORES_CONFIG)
+}
+
+/// The one config a tuple or map of `parts` is serialized with: that of the parts whose type
+/// depends on it, or of the first part if none does. Fails to compile if those parts disagree, as
+/// some of them would then be serialized differently from how they are typed.
+const fn merge_configs(parts: &[(SerializationConfig, bool)]) -> SerializationConfig {
+    let mut merged: Option<SerializationConfig> = None;
+    let mut i = 0;
+    while i < parts.len() {
+        let (config, ignores_config) = parts[i];
+        if !ignores_config {
+            match merged {
+                None => merged = Some(config),
+                Some(merged) if same_config(merged, config) => {}
+                Some(_) => panic!(
+                    "the parts of this tuple or map are serialized with different configs, which their Typescript types would not match"
+                ),
+            }
+        }
+        i += 1;
+    }
+
+    match merged {
+        Some(merged) => merged,
+        None => parts[0].0,
+    }
+}
+
+const fn same_config(a: SerializationConfig, b: SerializationConfig) -> bool {
+    a.backend.is_js() == b.backend.is_js()
+        && a.missing_as_null == b.missing_as_null
+        && a.hashmap_as_object == b.hashmap_as_object
+        && a.large_number_types_as_bigints == b.large_number_types_as_bigints
+        && a.bytes_as_arrays == b.bytes_as_arrays
+}
+
+macro_rules! impl_tsify_tuple {
+    ($first:ident $(, $elem:ident)*) => {
+        impl<$first: Tsify $(, $elem: Tsify)*> Tsify for ($first, $($elem,)*) {
+            type JsType = JsTypeOf<Self>;
+            const DECL: &'static str = "";
+            const SERIALIZATION_CONFIG: SerializationConfig =
+                merge_configs(&[part::<$first>() $(, part::<$elem>())*]);
+            const TYPESCRIPT_TYPE_IGNORES_CONFIG: bool = $first::TYPESCRIPT_TYPE_IGNORES_CONFIG
+                $(&& $elem::TYPESCRIPT_TYPE_IGNORES_CONFIG)*;
+            // `[`, `]`, and `, ` between the elements.
+            const TYPESCRIPT_TYPE_LEN: u32 =
+                2 + $first::TYPESCRIPT_TYPE_LEN $(+ 2 + $elem::TYPESCRIPT_TYPE_LEN)*;
+
+            fn describe_typescript_type() {
+                inform('[' as u32);
+                $first::describe_typescript_type();
+                $(
+                    inform_chars!(',', ' ');
+                    $elem::describe_typescript_type();
+                )*
+                inform(']' as u32);
+            }
+        }
+    };
+}
+
+impl_tsify_tuple!(A);
+impl_tsify_tuple!(A, B);
+impl_tsify_tuple!(A, B, C);
+impl_tsify_tuple!(A, B, C, D);
+impl_tsify_tuple!(A, B, C, D, E);
+impl_tsify_tuple!(A, B, C, D, E, F);
+impl_tsify_tuple!(A, B, C, D, E, F, G);
+impl_tsify_tuple!(A, B, C, D, E, F, G, H);
+
+/// A `Ts<T, C>` nested in another value is typed as the `Ts` itself, and always needs the `js`
+/// backend to pass through as is.
+#[cfg(feature = "js")]
+impl<T: Tsify, C: crate::TsConfig> Tsify for crate::Ts<T, C> {
+    type JsType = T::JsType;
+    const DECL: &'static str = "";
+    const SERIALIZATION_CONFIG: SerializationConfig = SerializationConfig {
+        backend: crate::Backend::Js,
+        ..T::SERIALIZATION_CONFIG
+    };
+    // A wrapper like `TsifyAsObject<T>` is not a union, whatever `T` is.
+    const TYPESCRIPT_TYPE_IS_UNION: bool =
+        T::TYPESCRIPT_TYPE_IS_UNION && C::TYPESCRIPT_TYPE_EXTRA_LEN == 0;
+    const TYPESCRIPT_TYPE_LEN: u32 = C::TYPESCRIPT_TYPE_EXTRA_LEN + T::TYPESCRIPT_TYPE_LEN;
+
+    fn describe_typescript_type() {
+        C::describe_typescript_type::<T>();
+    }
+}
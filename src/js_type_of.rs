@@ -0,0 +1,145 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::Tsify;
+use wasm_bindgen::convert::{
+    FromWasmAbi, IntoWasmAbi, LongRefFromWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi,
+    RefFromWasmAbi, VectorFromWasmAbi, VectorIntoWasmAbi,
+};
+use wasm_bindgen::describe::{inform, WasmDescribe, WasmDescribeVector, NAMED_EXTERNREF, VECTOR};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// The [`Tsify::JsType`] of the types `tsify` implements [`Tsify`] for, such as `Vec<T>`,
+/// `Option<T>` and `HashMap<K, V>`.
+///
+/// The derive imports a Javascript type named after the Typescript declaration, but
+/// `#[wasm_bindgen(typescript_type = ...)]` only takes a literal, so it cannot name `Foo[]` for
+/// every `Foo`. This is a plain JS handle whose Typescript type is spelled out from
+/// [`Tsify::describe_typescript_type`] instead.
+#[repr(transparent)]
+pub struct JsTypeOf<T>(JsValue, PhantomData<T>);
+
+impl<T> AsRef<JsValue> for JsTypeOf<T> {
+    fn as_ref(&self) -> &JsValue {
+        &self.0
+    }
+}
+
+impl<T> From<JsTypeOf<T>> for JsValue {
+    fn from(value: JsTypeOf<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> JsCast for JsTypeOf<T> {
+    // Any value may turn out to deserialize into `T`; only `from_js` can tell.
+    fn instanceof(_val: &JsValue) -> bool {
+        true
+    }
+
+    fn unchecked_from_js(val: JsValue) -> Self {
+        Self(val, PhantomData)
+    }
+
+    fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+        // SAFETY: `JsTypeOf<T>` is `#[repr(transparent)]` over `JsValue`.
+        unsafe { &*(val as *const JsValue as *const Self) }
+    }
+}
+
+impl<T> fmt::Debug for JsTypeOf<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("JsTypeOf").field(&self.0).finish()
+    }
+}
+
+impl<T> Clone for JsTypeOf<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Tsify> WasmDescribe for JsTypeOf<T> {
+    fn describe() {
+        inform(NAMED_EXTERNREF);
+        inform(T::TYPESCRIPT_TYPE_LEN);
+        T::describe_typescript_type();
+    }
+}
+
+impl<T: Tsify> IntoWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl<'a, T: Tsify> IntoWasmAbi for &'a JsTypeOf<T> {
+    type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        (&self.0).into_abi()
+    }
+}
+
+impl<T: Tsify> FromWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as FromWasmAbi>::Abi;
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self(JsValue::from_abi(js), PhantomData)
+    }
+}
+
+impl<T: Tsify> OptionIntoWasmAbi for JsTypeOf<T> {
+    fn none() -> Self::Abi {
+        <JsValue as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl<T: Tsify> OptionFromWasmAbi for JsTypeOf<T> {
+    fn is_none(abi: &Self::Abi) -> bool {
+        <JsValue as OptionFromWasmAbi>::is_none(abi)
+    }
+}
+
+impl<T: Tsify> RefFromWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as RefFromWasmAbi>::Abi;
+    type Anchor = ManuallyDrop<JsTypeOf<T>>;
+    unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        let js_value = ManuallyDrop::into_inner(JsValue::ref_from_abi(js));
+        ManuallyDrop::new(Self(js_value, PhantomData))
+    }
+}
+
+impl<T: Tsify> LongRefFromWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as LongRefFromWasmAbi>::Abi;
+    type Anchor = JsTypeOf<T>;
+    unsafe fn long_ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        Self(JsValue::long_ref_from_abi(js), PhantomData)
+    }
+}
+
+impl<T: Tsify> WasmDescribeVector for JsTypeOf<T> {
+    fn describe_vector() {
+        inform(VECTOR);
+        Self::describe();
+    }
+}
+
+impl<T: Tsify> VectorFromWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as VectorFromWasmAbi>::Abi;
+    unsafe fn vector_from_abi(js: Self::Abi) -> Box<[Self]> {
+        JsValue::vector_from_abi(js)
+            .into_vec()
+            .into_iter()
+            .map(JsCast::unchecked_from_js)
+            .collect()
+    }
+}
+
+impl<T: Tsify> VectorIntoWasmAbi for JsTypeOf<T> {
+    type Abi = <JsValue as VectorIntoWasmAbi>::Abi;
+    fn vector_into_abi(vector: Box<[Self]>) -> Self::Abi {
+        let values: Vec<JsValue> = vector.into_vec().into_iter().map(Into::into).collect();
+        JsValue::vector_into_abi(values.into_boxed_slice())
+    }
+}
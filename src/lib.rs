@@ -6,12 +6,44 @@ compile_error!(
     "Either the \"json\" or \"js\" feature must be enabled for tsify to function properly"
 );
 
+/// Informs wasm-bindgen of each character, which its descriptor interpreter cannot loop over.
+macro_rules! inform_chars {
+    ($($c:literal),*) => {
+        $(crate::describe::inform($c as u32);)*
+    };
+}
+
+mod borrow;
+#[cfg(feature = "wasm-bindgen")]
+#[doc(hidden)]
+pub mod describe;
+pub use borrow::BorrowBuffer;
 mod ts;
 pub use ts::Ts;
+mod ts_config;
+pub use ts_config::{AsDeclared, AsObject, TsConfig};
+mod de;
 mod error;
-pub use error::Error;
+mod ser;
+pub use error::{Error, SerializationError};
+#[doc(hidden)]
+pub mod fields;
+mod patch;
+pub use patch::{ApplyPatch, Patchable};
+mod impls;
+mod js_type_of;
+mod ts_fn;
+#[doc(hidden)]
+pub mod validate;
+pub use js_type_of::JsTypeOf;
+pub use ts_fn::{TsFn, TsFnArgs, TsFnReturn};
+pub use validate::{Mismatch, Validate};
+#[cfg(feature = "futures")]
+mod ts_promise;
+#[cfg(feature = "futures")]
+pub use ts_promise::TsPromise;
 
-#[cfg(all(feature = "json", not(feature = "js")))]
+#[cfg(feature = "json")]
 pub use gloo_utils::format::JsValueSerdeExt;
 #[cfg(feature = "js")]
 pub use serde_wasm_bindgen;
@@ -19,10 +51,61 @@ pub use tsify_macros::*;
 #[cfg(feature = "wasm-bindgen")]
 use wasm_bindgen::{JsCast, JsValue};
 
+/// Which serializer converts a type to and from JS, chosen with `#[tsify(backend = "json")]` or
+/// `#[tsify(backend = "js")]`.
+///
+/// Both can be compiled in at once, so a crate can pin the backend of its types regardless of
+/// which features other crates enable.
+///
+/// Nesting types with different backends is unsupported: the outermost type converts the whole
+/// value, while the declarations of the inner types follow their own backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// `serde_json`, through a JSON string. Ignores the rest of the [`SerializationConfig`].
+    #[cfg(feature = "json")]
+    Json,
+    /// `serde_wasm_bindgen`.
+    #[cfg(feature = "js")]
+    Js,
+}
+
+impl Backend {
+    /// The backend of types that don't choose one: `js` if the `js` feature is enabled, `json`
+    /// otherwise.
+    #[cfg(feature = "js")]
+    pub const DEFAULT: Self = Self::Js;
+    /// The backend of types that don't choose one: `js` if the `js` feature is enabled, `json`
+    /// otherwise.
+    #[cfg(not(feature = "js"))]
+    pub const DEFAULT: Self = Self::Json;
+
+    /// Whether this is `Backend::Js`.
+    pub const fn is_js(self) -> bool {
+        #[cfg(feature = "js")]
+        {
+            matches!(self, Self::Js)
+        }
+
+        #[cfg(not(feature = "js"))]
+        {
+            false
+        }
+    }
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct SerializationConfig {
+    pub backend: Backend,
     pub missing_as_null: bool,
     pub hashmap_as_object: bool,
     pub large_number_types_as_bigints: bool,
+    pub bytes_as_arrays: bool,
 }
 
 /// `Tsify` is a trait that allows you to convert a type to and from JavaScript.
@@ -36,50 +119,80 @@ pub trait Tsify {
 
     const DECL: &'static str;
     const SERIALIZATION_CONFIG: SerializationConfig = SerializationConfig {
+        backend: Backend::DEFAULT,
         missing_as_null: false,
         hashmap_as_object: false,
         large_number_types_as_bigints: false,
+        bytes_as_arrays: false,
     };
 
-    #[cfg(all(feature = "json", not(feature = "js")))]
+    /// Number of characters informed by [`Tsify::describe_typescript_type`].
+    #[cfg(feature = "wasm-bindgen")]
+    #[doc(hidden)]
+    const TYPESCRIPT_TYPE_LEN: u32 = 3;
+
+    /// Whether [`Tsify::describe_typescript_type`] informs a union, which needs parentheses to
+    /// be the element type of an array.
+    #[cfg(feature = "wasm-bindgen")]
+    #[doc(hidden)]
+    const TYPESCRIPT_TYPE_IS_UNION: bool = false;
+
+    /// Whether [`Tsify::describe_typescript_type`] is the same whatever config the value is
+    /// serialized with, as `string` is, so that the type can be part of a tuple or map with
+    /// another config.
+    #[cfg(feature = "wasm-bindgen")]
+    #[doc(hidden)]
+    const TYPESCRIPT_TYPE_IGNORES_CONFIG: bool = false;
+
+    /// Informs wasm-bindgen of the Typescript name of this type one character at a time, so that
+    /// [`TsFn`] can spell out its signature. wasm-bindgen cannot interpret a loop over a string
+    /// here, hence the generated code unrolls it. Defaults to `any`.
+    #[cfg(feature = "wasm-bindgen")]
+    #[doc(hidden)]
+    fn describe_typescript_type() {
+        use crate::describe::inform;
+        inform('a' as u32);
+        inform('n' as u32);
+        inform('y' as u32);
+    }
+
     #[inline]
-    fn into_js(&self) -> serde_json::Result<Self::JsType>
+    fn into_js(&self) -> Result<Self::JsType, SerializationError>
     where
         Self: serde::Serialize,
     {
-        JsValue::from_serde(self).map(JsCast::unchecked_from_js)
+        self.into_js_with(&Self::SERIALIZATION_CONFIG)
     }
 
-    #[cfg(all(feature = "json", not(feature = "js")))]
+    /// Like [`Tsify::into_js`], but with `config` in place of [`Tsify::SERIALIZATION_CONFIG`].
     #[inline]
-    fn from_js<T: Into<JsValue>>(js: T) -> serde_json::Result<Self>
+    fn into_js_with(&self, config: &SerializationConfig) -> Result<Self::JsType, SerializationError>
     where
-        Self: serde::de::DeserializeOwned,
+        Self: serde::Serialize,
     {
-        js.into().into_serde()
+        let js = ser::serialize(self, config)?;
+        Ok(js.unchecked_into())
     }
 
-    #[cfg(feature = "js")]
+    /// Like [`Tsify::from_js_tracked`], but returns only the [`SerializationError`].
     #[inline]
-    fn into_js(&self) -> Result<Self::JsType, serde_wasm_bindgen::Error>
+    fn from_js<T: Into<JsValue>>(js: T) -> Result<Self, SerializationError>
     where
-        Self: serde::Serialize,
+        Self: serde::de::DeserializeOwned,
     {
-        let config = <Self as Tsify>::SERIALIZATION_CONFIG;
-        let serializer = serde_wasm_bindgen::Serializer::new()
-            .serialize_missing_as_null(config.missing_as_null)
-            .serialize_maps_as_objects(config.hashmap_as_object)
-            .serialize_large_number_types_as_bigints(config.large_number_types_as_bigints);
-        self.serialize(&serializer).map(JsCast::unchecked_from_js)
+        Self::from_js_tracked(js.into()).map_err(Error::into_inner)
     }
 
-    #[cfg(feature = "js")]
-    #[inline]
-    fn from_js<T: Into<JsValue>>(js: T) -> Result<Self, serde_wasm_bindgen::Error>
+    /// Converts `js` into `Self`, returning an [`Error`] that names the type and, where it can,
+    /// the [path](Error::path) to the value that failed.
+    ///
+    /// This is the conversion from JS that [`Tsify::from_js`], [`Ts::to_rust`], [`TsFn`] and
+    /// `TsPromise` go through, so a manual impl that converts differently overrides this.
+    fn from_js_tracked(js: JsValue) -> Result<Self, Error>
     where
         Self: serde::de::DeserializeOwned,
     {
-        serde_wasm_bindgen::from_value(js.into())
+        de::from_js(js)
     }
 
     /// Calls `Ts::from_rust` on self, returning a `Result<Ts<Self>, crate::Error>`.
@@ -0,0 +1,25 @@
+use wasm_bindgen::JsValue;
+
+use crate::{Error, SerializationConfig, Tsify};
+
+/// A type with a generated patch type, whose fields are all optional. Derive it with
+/// `#[tsify(patch)]`.
+pub trait ApplyPatch {
+    /// The patch type, e.g. `FooPatch` for `Foo`.
+    type Patch;
+
+    /// Sets the fields of `self` that `patch` holds, leaving the rest as they are.
+    fn apply(&mut self, patch: Self::Patch);
+}
+
+/// The JS side of [`ApplyPatch`], for [`Ts::apply_patch`][crate::Ts::apply_patch] to write a
+/// patch into a JS value in place. Derived along with it.
+pub trait Patchable: Tsify + ApplyPatch {
+    /// Writes each field that is set in `patch` into the `Self` in `js`, serialized with
+    /// `config`.
+    fn write_patch(
+        js: &JsValue,
+        patch: &Self::Patch,
+        config: &SerializationConfig,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+#[cfg(feature = "json")]
+use gloo_utils::format::JsValueSerdeExt;
+
+use crate::{Backend, SerializationConfig, SerializationError};
+
+/// Converts `value` into JS with `config`, the way [`Tsify::into_js_with`] does for a whole
+/// type.
+///
+/// [`Tsify::into_js_with`]: crate::Tsify::into_js_with
+pub(crate) fn serialize<T: Serialize + ?Sized>(
+    value: &T,
+    config: &SerializationConfig,
+) -> Result<JsValue, SerializationError> {
+    match config.backend {
+        #[cfg(feature = "json")]
+        Backend::Json => Ok(JsValue::from_serde(value)?),
+        #[cfg(feature = "js")]
+        Backend::Js => {
+            let serializer = serde_wasm_bindgen::Serializer::new()
+                .serialize_missing_as_null(config.missing_as_null)
+                .serialize_maps_as_objects(config.hashmap_as_object)
+                .serialize_large_number_types_as_bigints(config.large_number_types_as_bigints)
+                .serialize_bytes_as_arrays(config.bytes_as_arrays);
+            Ok(value.serialize(&serializer)?)
+        }
+    }
+}
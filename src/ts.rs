@@ -1,14 +1,15 @@
 use std::fmt;
 use std::mem::ManuallyDrop;
 
-use crate::Error;
-use crate::Tsify;
+use crate::{
+    AsDeclared, BorrowBuffer, Error, Mismatch, Patchable, SerializationConfig, TsConfig, Tsify,
+    Validate,
+};
 use wasm_bindgen::convert::{
     FromWasmAbi, IntoWasmAbi, LongRefFromWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi,
     RefFromWasmAbi, VectorFromWasmAbi, VectorIntoWasmAbi,
 };
-use wasm_bindgen::describe::WasmDescribe;
-use wasm_bindgen::describe::WasmDescribeVector;
+use wasm_bindgen::describe::{inform, WasmDescribe, WasmDescribeVector, VECTOR};
 use wasm_bindgen::{JsCast, JsValue};
 
 /// A wrapper for a type implementing [`Tsify`], to be used in `#[wasm_bindgen]` function
@@ -26,6 +27,10 @@ use wasm_bindgen::{JsCast, JsValue};
 /// is robustness to serialization errors, especially when JavaScript (being a dynamic language)
 /// could call your functions with any kind of data at runtime. Panicking defeats this purpose.
 ///
+/// The second parameter picks the serialization config, and with it the Typescript type. It
+/// defaults to [`AsDeclared`], the config `T` was derived with; `Ts<T, AsObject>` instead turns
+/// every map in `T` into a plain object, see [`AsObject`][crate::AsObject].
+///
 /// ## Example
 ///
 /// ```
@@ -57,9 +62,9 @@ use wasm_bindgen::{JsCast, JsValue};
 /// ```
 ///
 #[repr(transparent)]
-pub struct Ts<T: Tsify>(<T as Tsify>::JsType, std::marker::PhantomData<T>);
+pub struct Ts<T: Tsify, C = AsDeclared>(<T as Tsify>::JsType, std::marker::PhantomData<(T, C)>);
 
-impl<T> Ts<T>
+impl<T, C> Ts<T, C>
 where
     T: Tsify,
 {
@@ -103,21 +108,25 @@ where
     pub fn new_unchecked(js: JsValue) -> Self {
         Self::new(js.unchecked_into())
     }
+
+    pub(crate) fn as_js(&self) -> &JsValue {
+        self.0.as_ref()
+    }
 }
 
-impl<T: Tsify> From<Ts<T>> for JsValue {
-    fn from(value: Ts<T>) -> Self {
+impl<T: Tsify, C> From<Ts<T, C>> for JsValue {
+    fn from(value: Ts<T, C>) -> Self {
         value.js_value()
     }
 }
 
-impl<T: Tsify> fmt::Debug for Ts<T> {
+impl<T: Tsify, C> fmt::Debug for Ts<T, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Ts").finish()
     }
 }
 
-impl<T> Clone for Ts<T>
+impl<T, C> Clone for Ts<T, C>
 where
     T: Tsify,
     <T as Tsify>::JsType: Clone,
@@ -127,19 +136,42 @@ where
     }
 }
 
-impl<T> WasmDescribe for Ts<T>
+/// Passes the JS value through untouched when serialized with `serde_wasm_bindgen`, so that a
+/// `Ts<T>` can be a field of a type that is itself converted. Any other serializer, including
+/// the `json` backend, cannot represent it.
+#[cfg(feature = "js")]
+impl<T: Tsify, C> serde::Serialize for Ts<T, C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_wasm_bindgen::preserve::serialize(self.0.as_ref(), serializer)
+    }
+}
+
+/// Takes the JS value as is when deserialized with `serde_wasm_bindgen`, leaving it to be
+/// converted later with [`Ts::to_rust`].
+#[cfg(feature = "js")]
+impl<'de, T: Tsify, C> serde::Deserialize<'de> for Ts<T, C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // `JsType` is only a Typescript name with no class to check `instanceof` against.
+        let js: JsValue = serde_wasm_bindgen::preserve::deserialize(deserializer)?;
+        Ok(Self::new_unchecked(js))
+    }
+}
+
+impl<T, C> WasmDescribe for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: WasmDescribe,
 {
     fn describe() {
-        <T as Tsify>::JsType::describe()
+        C::describe::<T>()
     }
 }
 
-impl<T> IntoWasmAbi for Ts<T>
+impl<T, C> IntoWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: IntoWasmAbi,
 {
     type Abi = <T::JsType as IntoWasmAbi>::Abi;
@@ -147,9 +179,10 @@ where
         self.0.into_abi()
     }
 }
-impl<'a, T> IntoWasmAbi for &'a Ts<T>
+impl<'a, T, C> IntoWasmAbi for &'a Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: JsCast + WasmDescribe,
 {
     type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
@@ -159,9 +192,10 @@ where
         self.0.unchecked_ref::<JsValue>().into_abi()
     }
 }
-impl<T> FromWasmAbi for Ts<T>
+impl<T, C> FromWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: FromWasmAbi,
 {
     type Abi = <T::JsType as FromWasmAbi>::Abi;
@@ -170,9 +204,10 @@ where
     }
 }
 
-impl<T> OptionIntoWasmAbi for Ts<T>
+impl<T, C> OptionIntoWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: OptionIntoWasmAbi,
 {
     fn none() -> Self::Abi {
@@ -180,9 +215,10 @@ where
     }
 }
 
-impl<T> OptionFromWasmAbi for Ts<T>
+impl<T, C> OptionFromWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: OptionFromWasmAbi,
 {
     fn is_none(abi: &Self::Abi) -> bool {
@@ -190,13 +226,14 @@ where
     }
 }
 
-impl<T> RefFromWasmAbi for Ts<T>
+impl<T, C> RefFromWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: RefFromWasmAbi,
 {
     // JsValue uses ManuallyDrop.
-    type Anchor = ManuallyDrop<Ts<T>>;
+    type Anchor = ManuallyDrop<Ts<T, C>>;
     type Abi = <JsValue as RefFromWasmAbi>::Abi; // i.e. u32
     unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
         let js_type_anchor = JsValue::ref_from_abi(js);
@@ -206,13 +243,14 @@ where
     }
 }
 
-impl<T> LongRefFromWasmAbi for Ts<T>
+impl<T, C> LongRefFromWasmAbi for Ts<T, C>
 where
     T: Tsify,
+    C: TsConfig,
     <T as Tsify>::JsType: LongRefFromWasmAbi,
 {
     type Abi = <JsValue as LongRefFromWasmAbi>::Abi;
-    type Anchor = Ts<T>;
+    type Anchor = Ts<T, C>;
     unsafe fn long_ref_from_abi(js: Self::Abi) -> Self::Anchor {
         let js_value = <JsValue as LongRefFromWasmAbi>::long_ref_from_abi(js);
         let js_type: <T as Tsify>::JsType = js_value.unchecked_into();
@@ -220,20 +258,23 @@ where
     }
 }
 
-impl<T> WasmDescribeVector for Ts<T>
+impl<T, C> WasmDescribeVector for Ts<T, C>
 where
     T: Tsify,
-    <T as Tsify>::JsType: WasmDescribeVector,
+    C: TsConfig,
+    <T as Tsify>::JsType: WasmDescribe,
 {
     fn describe_vector() {
-        <T as Tsify>::JsType::describe_vector()
+        inform(VECTOR);
+        C::describe::<T>()
     }
 }
 
-impl<T> VectorFromWasmAbi for Ts<T>
+impl<T, C> VectorFromWasmAbi for Ts<T, C>
 where
     T: Tsify,
-    <T as Tsify>::JsType: VectorFromWasmAbi,
+    C: TsConfig,
+    <T as Tsify>::JsType: VectorFromWasmAbi + WasmDescribe,
 {
     type Abi = <<T as Tsify>::JsType as VectorFromWasmAbi>::Abi;
 
@@ -242,15 +283,16 @@ where
         let vec = Vec::from(vec_js);
         vec.into_iter()
             .map(|js_item| Ts(js_item, std::marker::PhantomData))
-            .collect::<Vec<Ts<T>>>()
+            .collect::<Vec<Self>>()
             .into_boxed_slice()
     }
 }
 
-impl<T> VectorIntoWasmAbi for Ts<T>
+impl<T, C> VectorIntoWasmAbi for Ts<T, C>
 where
     T: Tsify,
-    <T as Tsify>::JsType: VectorIntoWasmAbi,
+    C: TsConfig,
+    <T as Tsify>::JsType: VectorIntoWasmAbi + WasmDescribe,
 {
     type Abi = <<T as Tsify>::JsType as VectorIntoWasmAbi>::Abi;
 
@@ -264,28 +306,96 @@ where
     }
 }
 
-impl<T: Tsify + serde::de::DeserializeOwned> Ts<T>
+impl<T: Tsify + serde::de::DeserializeOwned, C> Ts<T, C>
 where
     <T as Tsify>::JsType: Clone,
 {
     /// Converts the inner JSType (e.g. JsValue) into T
     pub fn to_rust(&self) -> Result<T, Error> {
-        T::from_js(self.0.clone()).map_err(|inner| Error {
-            type_name: std::any::type_name::<T>(),
-            de: true,
-            inner,
-        })
+        T::from_js_tracked(self.0.unchecked_ref::<JsValue>().clone())
     }
 }
 
-impl<T: Tsify + serde::Serialize> Ts<T> {
-    /// Converts a rust type T into to the inner JSType (e.g. JsValue)
+impl<T: Tsify, C> Ts<T, C> {
+    /// Like [`Ts::to_rust`], for types that borrow from the input, such as `&'a str` and
+    /// `Cow<'a, str>` fields. `T`'s backend reads the value as `to_rust` would, and what the
+    /// result borrows is kept in `buffer`: the JSON text for the `json` backend, and each string
+    /// or byte array for the `js` backend.
+    ///
+    /// `U` is `T` with the lifetime of `buffer`; any type with the same JS type as `T` is
+    /// accepted. With the `json` backend, a `&'a str` cannot borrow a JSON string with escapes in
+    /// it, so prefer `#[serde(borrow)] Cow<'a, str>` where those can occur.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use tsify::{BorrowBuffer, Ts, Tsify};
+    /// use wasm_bindgen::prelude::*;
+    ///
+    /// #[derive(Tsify, serde::Deserialize)]
+    /// pub struct Token<'a> {
+    ///     kind: &'a str,
+    ///     #[serde(borrow)]
+    ///     text: Cow<'a, str>,
+    /// }
+    ///
+    /// #[wasm_bindgen]
+    /// pub fn token_len(token: Ts<Token<'static>>) -> Result<usize, JsError> {
+    ///     let mut buffer = BorrowBuffer::new();
+    ///     let token: Token<'_> = token.to_rust_borrowed(&mut buffer)?;
+    ///     Ok(token.text.len())
+    /// }
+    /// ```
+    pub fn to_rust_borrowed<'a, U>(&self, buffer: &'a mut BorrowBuffer) -> Result<U, Error>
+    where
+        U: Tsify<JsType = T::JsType> + serde::Deserialize<'a>,
+    {
+        crate::de::from_js_borrowed::<T, U>(self.0.as_ref(), buffer)
+    }
+}
+
+impl<T: Tsify + Validate, C> Ts<T, C> {
+    /// Checks that the inner value has the shape of `T`, without deserializing it. Reports the
+    /// first mismatch like [`Ts::to_rust`] would, with the path to it.
+    ///
+    /// `T` needs `#[tsify(validate)]`. Fields with a `type` override or a custom
+    /// `deserialize_with` are not checked.
+    pub fn validate(&self) -> Result<(), Error> {
+        T::validate(self.0.as_ref(), T::SERIALIZATION_CONFIG.backend)
+            .map_err(Mismatch::into_error::<T>)
+    }
+}
+
+impl<T: Patchable, C: TsConfig> Ts<T, C> {
+    /// Writes the fields set in `patch` into the inner value, in place. The JS object keeps its
+    /// identity and the properties the patch leaves out are not touched, unlike converting a
+    /// changed `T` with [`Ts::from_rust`].
+    ///
+    /// `T` needs `#[tsify(patch)]`, which generates `T::Patch`.
+    pub fn apply_patch(&self, patch: &T::Patch) -> Result<(), Error> {
+        T::write_patch(self.0.as_ref(), patch, &C::config(T::SERIALIZATION_CONFIG))
+    }
+}
+
+impl<T: Tsify + serde::Serialize, C: TsConfig> Ts<T, C> {
+    /// Converts a rust type T into to the inner JSType (e.g. JsValue), with the config chosen by
+    /// `C`.
     pub fn from_rust(rust: &T) -> Result<Self, Error> {
-        let js_type = T::into_js(rust).map_err(|inner| Error {
-            type_name: std::any::type_name::<T>(),
-            de: false,
-            inner,
-        })?;
+        Self::from_rust_with(rust, &C::config(T::SERIALIZATION_CONFIG))
+    }
+
+    /// Like [`Ts::from_rust`], but with `config` in place of the one chosen by `C`. Nothing
+    /// checks that the Typescript type of `Ts<T, C>` still matches.
+    ///
+    /// A `config` equal to `T`'s own goes through [`Tsify::into_js`], so that a manual impl
+    /// overriding it is still used.
+    pub fn from_rust_with(rust: &T, config: &SerializationConfig) -> Result<Self, Error> {
+        let js_type = if *config == T::SERIALIZATION_CONFIG {
+            T::into_js(rust)
+        } else {
+            T::into_js_with(rust, config)
+        };
+        let js_type = js_type.map_err(Error::serialization::<T>)?;
         Ok(Self::new(js_type))
     }
 }
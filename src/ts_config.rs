@@ -0,0 +1,95 @@
+use wasm_bindgen::describe::{inform, WasmDescribe, NAMED_EXTERNREF};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{SerializationConfig, Tsify};
+
+/// Chooses how a [`Ts<T, C>`][crate::Ts] is serialized, and the Typescript type that goes with
+/// it.
+pub trait TsConfig {
+    /// The config to serialize `T` with, given the one it was derived with.
+    fn config(declared: SerializationConfig) -> SerializationConfig;
+
+    /// Informs wasm-bindgen of the whole descriptor of a `Ts<T, Self>`.
+    #[doc(hidden)]
+    fn describe<T: Tsify>()
+    where
+        T::JsType: WasmDescribe;
+
+    /// Number of characters [`TsConfig::describe_typescript_type`] adds to those of `T`.
+    #[doc(hidden)]
+    const TYPESCRIPT_TYPE_EXTRA_LEN: u32;
+
+    /// Informs the Typescript name of a `Ts<T, Self>` one character at a time, like
+    /// [`Tsify::describe_typescript_type`].
+    #[doc(hidden)]
+    fn describe_typescript_type<T: Tsify>();
+}
+
+/// The default for [`Ts<T>`][crate::Ts]: `T` is serialized with the config it was derived with,
+/// and typed as declared.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsDeclared;
+
+impl TsConfig for AsDeclared {
+    fn config(declared: SerializationConfig) -> SerializationConfig {
+        declared
+    }
+
+    fn describe<T: Tsify>()
+    where
+        T::JsType: WasmDescribe,
+    {
+        T::JsType::describe()
+    }
+
+    const TYPESCRIPT_TYPE_EXTRA_LEN: u32 = 0;
+
+    fn describe_typescript_type<T: Tsify>() {
+        T::describe_typescript_type();
+    }
+}
+
+/// Serializes every map in `T` as a plain object, as if `T` were derived with
+/// `#[tsify(hashmap_as_object)]`. A `Ts<T, AsObject>` is typed as `TsifyAsObject<T>`, which
+/// turns every `Map<K, V>` in `T` into a `Record<K, V>`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsObject;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_AS_OBJECT: &'static str = r#"
+/** `T` serialized with every `Map<K, V>` in it as a plain object, see `tsify::AsObject`. */
+export type TsifyAsObject<T> = T extends Map<infer K, infer V>
+    ? Record<K & PropertyKey, TsifyAsObject<V>>
+    : T extends Function | Uint8Array
+    ? T
+    : T extends object
+    ? { [P in keyof T]: TsifyAsObject<T[P]> }
+    : T;
+"#;
+
+impl TsConfig for AsObject {
+    fn config(declared: SerializationConfig) -> SerializationConfig {
+        SerializationConfig {
+            hashmap_as_object: true,
+            ..declared
+        }
+    }
+
+    fn describe<T: Tsify>()
+    where
+        T::JsType: WasmDescribe,
+    {
+        inform(NAMED_EXTERNREF);
+        inform(Self::TYPESCRIPT_TYPE_EXTRA_LEN + T::TYPESCRIPT_TYPE_LEN);
+        Self::describe_typescript_type::<T>();
+    }
+
+    // `TsifyAsObject<` and `>`.
+    const TYPESCRIPT_TYPE_EXTRA_LEN: u32 = 14 + 1;
+
+    fn describe_typescript_type<T: Tsify>() {
+        inform_chars!('T', 's', 'i', 'f', 'y', 'A', 's', 'O', 'b', 'j', 'e', 'c', 't', '<');
+        T::describe_typescript_type();
+        crate::describe::inform('>' as u32);
+    }
+}
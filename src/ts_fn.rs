@@ -0,0 +1,285 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::error::{exception_to_error, Direction};
+use crate::Error;
+use crate::Tsify;
+use wasm_bindgen::convert::{
+    FromWasmAbi, IntoWasmAbi, LongRefFromWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi,
+    RefFromWasmAbi,
+};
+use wasm_bindgen::describe::{inform, WasmDescribe, NAMED_EXTERNREF};
+use wasm_bindgen::JsValue;
+
+/// A JavaScript function taking the [`Tsify`] types `Args` and returning `Ret`, to be used in
+/// `#[wasm_bindgen]` function parameters and return types in place of [`js_sys::Function`].
+///
+/// `Args` is a tuple of up to eight types and `Ret` is either `()` or a [`Tsify`] type. The
+/// generated Typescript signature is spelled out from them, e.g. `TsFn<(Point, Line), Point>`
+/// becomes `(arg0: Point, arg1: Line) => Point`. Types implementing `Tsify` by hand are named
+/// `any`.
+///
+/// [`call`][TsFn::call] serializes the arguments, calls the function and deserializes what it
+/// returns, reporting failures as a [`tsify::Error`][Error] rather than panicking.
+///
+/// ## Example
+///
+/// ```
+/// use tsify::{Ts, TsFn, Tsify};
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::JsError;
+///
+/// #[derive(Tsify, serde::Deserialize, serde::Serialize)]
+/// pub struct Vec2 {
+///    x: f64,
+///    y: f64,
+/// }
+///
+/// #[wasm_bindgen]
+/// pub fn map_point(v: Ts<Vec2>, f: TsFn<(Vec2,), Vec2>) -> Result<Ts<Vec2>, JsError> {
+///     let mapped = f.call((v.to_rust()?,))?;
+///     Ok(mapped.into_ts()?)
+/// }
+/// ```
+///
+#[repr(transparent)]
+pub struct TsFn<Args, Ret = ()>(js_sys::Function, PhantomData<fn(Args) -> Ret>);
+
+impl<Args, Ret> TsFn<Args, Ret> {
+    /// Treat `function` as taking `Args` and returning `Ret` without any checks.
+    ///
+    /// If you get this wrong, the worst that can happen is that [`call()`][Self::call] fails to
+    /// deserialize the return value.
+    pub fn new_unchecked(function: js_sys::Function) -> Self {
+        Self(function, PhantomData)
+    }
+
+    /// Returns the underlying JS function.
+    pub fn as_function(&self) -> &js_sys::Function {
+        &self.0
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> TsFn<Args, Ret> {
+    const SIGNATURE_LEN: u32 = 1 + Args::PARAMS_LEN + 5 + Ret::TYPESCRIPT_TYPE_LEN;
+
+    /// Calls the function with `args`, returning what it returns or the exception it threw.
+    pub fn call(&self, args: Args) -> Result<Ret, Error> {
+        let args = args.into_js_args()?;
+        let ret = self
+            .0
+            .apply(&JsValue::UNDEFINED, &args)
+            .map_err(|exception| {
+                Error::new::<Self>(Direction::Call, exception_to_error(exception))
+            })?;
+        Ret::from_js_return(ret)
+    }
+}
+
+impl<Args, Ret> From<TsFn<Args, Ret>> for JsValue {
+    fn from(value: TsFn<Args, Ret>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<Args, Ret> fmt::Debug for TsFn<Args, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TsFn").finish()
+    }
+}
+
+impl<Args, Ret> Clone for TsFn<Args, Ret> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> WasmDescribe for TsFn<Args, Ret> {
+    fn describe() {
+        inform(NAMED_EXTERNREF);
+        inform(Self::SIGNATURE_LEN);
+        inform('(' as u32);
+        Args::describe_params();
+        inform(')' as u32);
+        inform(' ' as u32);
+        inform('=' as u32);
+        inform('>' as u32);
+        inform(' ' as u32);
+        Ret::describe_typescript_type();
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> IntoWasmAbi for TsFn<Args, Ret> {
+    type Abi = <js_sys::Function as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl<'a, Args: TsFnArgs, Ret: TsFnReturn> IntoWasmAbi for &'a TsFn<Args, Ret> {
+    type Abi = <&'a js_sys::Function as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        (&self.0).into_abi()
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> FromWasmAbi for TsFn<Args, Ret> {
+    type Abi = <js_sys::Function as FromWasmAbi>::Abi;
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self::new_unchecked(js_sys::Function::from_abi(js))
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> OptionIntoWasmAbi for TsFn<Args, Ret> {
+    fn none() -> Self::Abi {
+        <js_sys::Function as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> OptionFromWasmAbi for TsFn<Args, Ret> {
+    fn is_none(abi: &Self::Abi) -> bool {
+        <js_sys::Function as OptionFromWasmAbi>::is_none(abi)
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> RefFromWasmAbi for TsFn<Args, Ret> {
+    type Abi = <js_sys::Function as RefFromWasmAbi>::Abi;
+    type Anchor = ManuallyDrop<TsFn<Args, Ret>>;
+    unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        let function = ManuallyDrop::into_inner(js_sys::Function::ref_from_abi(js));
+        ManuallyDrop::new(Self::new_unchecked(function))
+    }
+}
+
+impl<Args: TsFnArgs, Ret: TsFnReturn> LongRefFromWasmAbi for TsFn<Args, Ret> {
+    type Abi = <js_sys::Function as LongRefFromWasmAbi>::Abi;
+    type Anchor = TsFn<Args, Ret>;
+    unsafe fn long_ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        Self::new_unchecked(js_sys::Function::long_ref_from_abi(js))
+    }
+}
+
+/// The arguments of a [`TsFn`]: a tuple of up to eight [`Tsify`] types.
+pub trait TsFnArgs {
+    /// Number of characters informed by [`TsFnArgs::describe_params`].
+    #[doc(hidden)]
+    const PARAMS_LEN: u32;
+
+    /// Informs wasm-bindgen of the parameter list, e.g. `arg0: Foo, arg1: Bar`.
+    #[doc(hidden)]
+    fn describe_params();
+
+    #[doc(hidden)]
+    fn into_js_args(self) -> Result<js_sys::Array, Error>;
+}
+
+impl TsFnArgs for () {
+    const PARAMS_LEN: u32 = 0;
+
+    fn describe_params() {}
+
+    fn into_js_args(self) -> Result<js_sys::Array, Error> {
+        Ok(js_sys::Array::new())
+    }
+}
+
+fn arg_into_js<T: Tsify + serde::Serialize>(arg: &T) -> Result<JsValue, Error> {
+    arg.into_js()
+        .map(Into::into)
+        .map_err(Error::serialization::<T>)
+}
+
+/// Informs `arg{digit}: ` one character at a time.
+fn describe_param_name(digit: char) {
+    inform('a' as u32);
+    inform('r' as u32);
+    inform('g' as u32);
+    inform(digit as u32);
+    inform(':' as u32);
+    inform(' ' as u32);
+}
+
+macro_rules! impl_ts_fn_args {
+    ($first_index:tt $first_digit:literal $first:ident $(, $index:tt $digit:literal $arg:ident)*) => {
+        impl<$first, $($arg),*> TsFnArgs for ($first, $($arg,)*)
+        where
+            $first: Tsify + serde::Serialize,
+            $($arg: Tsify + serde::Serialize,)*
+        {
+            // `arg0: ` is 6 characters, and every further parameter is preceded by `, `.
+            const PARAMS_LEN: u32 =
+                6 + $first::TYPESCRIPT_TYPE_LEN $(+ 8 + $arg::TYPESCRIPT_TYPE_LEN)*;
+
+            fn describe_params() {
+                describe_param_name($first_digit);
+                $first::describe_typescript_type();
+                $(
+                    inform(',' as u32);
+                    inform(' ' as u32);
+                    describe_param_name($digit);
+                    $arg::describe_typescript_type();
+                )*
+            }
+
+            fn into_js_args(self) -> Result<js_sys::Array, Error> {
+                let args = js_sys::Array::new();
+                args.push(&arg_into_js(&self.$first_index)?);
+                $(args.push(&arg_into_js(&self.$index)?);)*
+                Ok(args)
+            }
+        }
+    };
+}
+
+impl_ts_fn_args!(0 '0' A);
+impl_ts_fn_args!(0 '0' A, 1 '1' B);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C, 3 '3' D);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C, 3 '3' D, 4 '4' E);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C, 3 '3' D, 4 '4' E, 5 '5' F);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C, 3 '3' D, 4 '4' E, 5 '5' F, 6 '6' G);
+impl_ts_fn_args!(0 '0' A, 1 '1' B, 2 '2' C, 3 '3' D, 4 '4' E, 5 '5' F, 6 '6' G, 7 '7' H);
+
+/// The return type of a [`TsFn`]: `()` for `void`, or a [`Tsify`] type.
+pub trait TsFnReturn: Sized {
+    /// Number of characters informed by [`TsFnReturn::describe_typescript_type`].
+    #[doc(hidden)]
+    const TYPESCRIPT_TYPE_LEN: u32;
+
+    #[doc(hidden)]
+    fn describe_typescript_type();
+
+    #[doc(hidden)]
+    fn from_js_return(js: JsValue) -> Result<Self, Error>;
+}
+
+impl TsFnReturn for () {
+    const TYPESCRIPT_TYPE_LEN: u32 = 4;
+
+    fn describe_typescript_type() {
+        inform('v' as u32);
+        inform('o' as u32);
+        inform('i' as u32);
+        inform('d' as u32);
+    }
+
+    fn from_js_return(_js: JsValue) -> Result<Self, Error> {
+        Ok(())
+    }
+}
+
+impl<T> TsFnReturn for T
+where
+    T: Tsify + serde::de::DeserializeOwned,
+{
+    const TYPESCRIPT_TYPE_LEN: u32 = <T as Tsify>::TYPESCRIPT_TYPE_LEN;
+
+    fn describe_typescript_type() {
+        <T as Tsify>::describe_typescript_type()
+    }
+
+    fn from_js_return(js: JsValue) -> Result<Self, Error> {
+        T::from_js_tracked(js)
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::error::{exception_to_error, Direction};
+use crate::Error;
+use crate::Ts;
+use crate::Tsify;
+use wasm_bindgen::convert::{
+    FromWasmAbi, IntoWasmAbi, LongRefFromWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi,
+    RefFromWasmAbi,
+};
+use wasm_bindgen::describe::{inform, WasmDescribe, NAMED_EXTERNREF};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+
+/// A JavaScript `Promise` resolving to a type implementing [`Tsify`], to be used in
+/// `#[wasm_bindgen]` function parameters and return types in place of [`js_sys::Promise`].
+///
+/// The generated Typescript type is `Promise<T>`. Use [`TsPromise::from_future`] to hand a Rust
+/// future to JS, and [`to_rust()`][TsPromise::to_rust] to await a promise from JS.
+///
+/// Requires the `futures` feature.
+///
+/// ## Example
+///
+/// ```
+/// use tsify::{Tsify, TsPromise};
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::JsError;
+///
+/// #[derive(Tsify, serde::Deserialize, serde::Serialize)]
+/// pub struct Vec2 {
+///    x: f64,
+///    y: f64,
+/// }
+///
+/// #[wasm_bindgen]
+/// pub fn origin() -> TsPromise<Vec2> {
+///     TsPromise::from_future(async { Vec2 { x: 0.0, y: 0.0 } })
+/// }
+///
+/// #[wasm_bindgen]
+/// pub async fn length(v: TsPromise<Vec2>) -> Result<f64, JsError> {
+///     let Vec2 { x, y } = v.to_rust().await?;
+///     Ok(x.hypot(y))
+/// }
+/// ```
+///
+#[repr(transparent)]
+pub struct TsPromise<T>(js_sys::Promise, PhantomData<T>);
+
+impl<T> TsPromise<T> {
+    /// Treat `promise` as resolving to `T` without any checks.
+    ///
+    /// If you get this wrong, the worst that can happen is that [`to_rust()`][Self::to_rust]
+    /// fails to deserialize the resolved value.
+    pub fn new_unchecked(promise: js_sys::Promise) -> Self {
+        Self(promise, PhantomData)
+    }
+
+    /// Returns the underlying JS promise.
+    pub fn as_promise(&self) -> &js_sys::Promise {
+        &self.0
+    }
+}
+
+impl<T: Tsify> TsPromise<T> {
+    const TYPESCRIPT_TYPE_LEN: u32 = 8 + T::TYPESCRIPT_TYPE_LEN + 1;
+}
+
+impl<T: Tsify + serde::Serialize + 'static> TsPromise<T> {
+    /// Runs `future` in the background, resolving the returned promise with its serialized
+    /// output. The promise is rejected with a `TsifyError` if serialization fails.
+    pub fn from_future<F>(future: F) -> Self
+    where
+        F: Future<Output = T> + 'static,
+    {
+        Self::from_try_future(async move { Ok::<_, JsValue>(future.await) })
+    }
+
+    /// Like [`TsPromise::from_future`], but rejects the promise with the error if `future`
+    /// fails.
+    pub fn from_try_future<F, E>(future: F) -> Self
+    where
+        F: Future<Output = Result<T, E>> + 'static,
+        E: Into<JsValue>,
+    {
+        Self::new_unchecked(wasm_bindgen_futures::future_to_promise(async move {
+            let value = future.await.map_err(Into::into)?;
+            let ts = Ts::<T>::from_rust(&value).map_err(JsValue::from)?;
+            Ok(ts.into())
+        }))
+    }
+}
+
+impl<T: Tsify + serde::de::DeserializeOwned> TsPromise<T> {
+    /// Waits for the promise to settle, then converts the resolved value into `T`.
+    pub async fn to_rust(&self) -> Result<T, Error> {
+        let value = JsFuture::from(self.0.clone())
+            .await
+            .map_err(|reason| Error::new::<Self>(Direction::Reject, exception_to_error(reason)))?;
+
+        T::from_js_tracked(value)
+    }
+}
+
+impl<T> From<TsPromise<T>> for JsValue {
+    fn from(value: TsPromise<T>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<T> fmt::Debug for TsPromise<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TsPromise").finish()
+    }
+}
+
+impl<T> Clone for TsPromise<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Tsify> WasmDescribe for TsPromise<T> {
+    fn describe() {
+        inform(NAMED_EXTERNREF);
+        inform(Self::TYPESCRIPT_TYPE_LEN);
+        inform('P' as u32);
+        inform('r' as u32);
+        inform('o' as u32);
+        inform('m' as u32);
+        inform('i' as u32);
+        inform('s' as u32);
+        inform('e' as u32);
+        inform('<' as u32);
+        T::describe_typescript_type();
+        inform('>' as u32);
+    }
+}
+
+impl<T: Tsify> IntoWasmAbi for TsPromise<T> {
+    type Abi = <js_sys::Promise as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        self.0.into_abi()
+    }
+}
+
+impl<'a, T: Tsify> IntoWasmAbi for &'a TsPromise<T> {
+    type Abi = <&'a js_sys::Promise as IntoWasmAbi>::Abi;
+    fn into_abi(self) -> Self::Abi {
+        (&self.0).into_abi()
+    }
+}
+
+impl<T: Tsify> FromWasmAbi for TsPromise<T> {
+    type Abi = <js_sys::Promise as FromWasmAbi>::Abi;
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self::new_unchecked(js_sys::Promise::from_abi(js))
+    }
+}
+
+impl<T: Tsify> OptionIntoWasmAbi for TsPromise<T> {
+    fn none() -> Self::Abi {
+        <js_sys::Promise as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl<T: Tsify> OptionFromWasmAbi for TsPromise<T> {
+    fn is_none(abi: &Self::Abi) -> bool {
+        <js_sys::Promise as OptionFromWasmAbi>::is_none(abi)
+    }
+}
+
+impl<T: Tsify> RefFromWasmAbi for TsPromise<T> {
+    type Abi = <js_sys::Promise as RefFromWasmAbi>::Abi;
+    type Anchor = ManuallyDrop<TsPromise<T>>;
+    unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        let promise = ManuallyDrop::into_inner(js_sys::Promise::ref_from_abi(js));
+        ManuallyDrop::new(Self::new_unchecked(promise))
+    }
+}
+
+impl<T: Tsify> LongRefFromWasmAbi for TsPromise<T> {
+    type Abi = <js_sys::Promise as LongRefFromWasmAbi>::Abi;
+    type Anchor = TsPromise<T>;
+    unsafe fn long_ref_from_abi(js: Self::Abi) -> Self::Anchor {
+        Self::new_unchecked(js_sys::Promise::long_ref_from_abi(js))
+    }
+}
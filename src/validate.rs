@@ -0,0 +1,651 @@
+//! Checks that a `JsValue` has the shape serde expects of a type, without deserializing it.
+//!
+//! [`Validate`] is implemented here for the standard library types serde supports, and by
+//! `#[derive(Tsify)]` with `#[tsify(validate)]`, whose generated code calls the functions below.
+//! It follows serde's own layout of structs and enums rather than the Typescript declaration, so
+//! renames, defaults, tagging and flattening are checked the way `to_rust` would see them. See
+//! [`Validate`] for where it is stricter than `to_rust`.
+
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use js_sys::{Array, Map, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::{Backend, Error, SerializationError, Tsify};
+
+/// A type whose shape [`Ts::validate`][crate::Ts::validate] can check without deserializing
+/// it. Derive it with `#[tsify(validate)]`.
+///
+/// A value that passes is one `to_rust` reads, short of what the checks cannot see: fields with
+/// `deserialize_with` or `#[tsify(type)]` and types converted with `#[serde(from)]` are taken as
+/// they are. A few forms serde also reads are rejected, so that a value `to_rust` reads may still
+/// fail:
+///
+/// - an internally tagged enum written as an array, with the tag first,
+/// - a struct variant of an adjacently tagged enum written as an array, which serde-wasm-bindgen
+///   reads only if the content comes before the tag.
+pub trait Validate {
+    /// Checks `js` against the shape of `Self` as `backend` reads it, returning the first
+    /// mismatch. The backend is that of the outermost type, which converts the whole value.
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch>;
+}
+
+/// Where and why a value does not have the shape of a [`Validate`] type.
+#[derive(Debug)]
+pub struct Mismatch {
+    /// Innermost first, as the error travels outwards.
+    path: Vec<Segment>,
+    message: String,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Key(String),
+    Index(u32),
+}
+
+impl Mismatch {
+    /// A mismatch described by `message`, e.g. `missing field \`x\``.
+    pub fn custom(message: impl fmt::Display) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.to_string(),
+        }
+    }
+
+    /// A value of the wrong type, e.g. `invalid type: string "x", expected u32`.
+    pub fn invalid_type(found: &JsValue, expected: &str) -> Self {
+        Self::custom(format_args!(
+            "invalid type: {}, expected {expected}",
+            Unexpected(found)
+        ))
+    }
+
+    /// Records that the mismatch is inside the property `key`.
+    pub fn at_key(mut self, key: &str) -> Self {
+        self.path.push(Segment::Key(key.to_string()));
+        self
+    }
+
+    /// Records that the mismatch is inside the element `index`.
+    pub fn at_index(mut self, index: u32) -> Self {
+        self.path.push(Segment::Index(index));
+        self
+    }
+
+    /// Formats the path like `items[3].price`, the same as deserialization errors.
+    fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.path.iter().rev() {
+            match segment {
+                Segment::Key(key) if path.is_empty() => path.push_str(key),
+                Segment::Key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                Segment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+        path
+    }
+
+    /// An [`Error`] as if `T` had failed to deserialize, with the error type of its backend.
+    pub(crate) fn into_error<T: Tsify>(self) -> Error {
+        let inner: SerializationError = match T::SERIALIZATION_CONFIG.backend {
+            #[cfg(feature = "json")]
+            Backend::Json => <serde_json::Error as serde::de::Error>::custom(&self.message).into(),
+            #[cfg(feature = "js")]
+            Backend::Js => {
+                <serde_wasm_bindgen::Error as serde::de::Error>::custom(&self.message).into()
+            }
+        };
+
+        Error {
+            path: self.path(),
+            ..Error::deserialization::<T>(inner)
+        }
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path() {
+            path if path.is_empty() => f.write_str(&self.message),
+            path => write!(f, "{} at `{path}`", self.message),
+        }
+    }
+}
+
+/// Describes a value the way serde's `Unexpected` does, e.g. `string "foo"`.
+struct Unexpected<'a>(&'a JsValue);
+
+impl fmt::Display for Unexpected<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let js = self.0;
+        if js.is_null() {
+            f.write_str("null")
+        } else if js.is_undefined() {
+            f.write_str("undefined")
+        } else if let Some(b) = js.as_bool() {
+            write!(f, "boolean `{b}`")
+        } else if let Some(n) = js.as_f64() {
+            write!(f, "number `{n}`")
+        } else if let Some(s) = js.as_string() {
+            write!(f, "string {s:?}")
+        } else if js.is_bigint() {
+            f.write_str("bigint")
+        } else if Array::is_array(js) {
+            f.write_str("array")
+        } else if js.is_instance_of::<Map>() {
+            f.write_str("map")
+        } else if js.is_function() {
+            f.write_str("function")
+        } else {
+            f.write_str("object")
+        }
+    }
+}
+
+/// Checks that `js` is an object, for a struct or struct variant.
+pub fn object<'a>(js: &'a JsValue, expected: &str) -> Result<&'a JsValue, Mismatch> {
+    if js.is_object() && !Array::is_array(js) && !js.is_function() {
+        Ok(js)
+    } else {
+        Err(Mismatch::invalid_type(js, expected))
+    }
+}
+
+/// Checks the property named `keys[0]`, or any of its aliases. A missing property is fine if
+/// the field has a default, or if `T` accepts `undefined` as `Option` does.
+pub fn field<T: Validate + ?Sized>(
+    object: &JsValue,
+    keys: &[&str],
+    has_default: bool,
+    backend: Backend,
+) -> Result<(), Mismatch> {
+    let found = keys
+        .iter()
+        .map(|key| (key, property(object, key)))
+        .find(|(_, value)| !value.is_undefined());
+
+    match found {
+        Some((key, value)) => T::validate(&value, backend).map_err(|m| m.at_key(key)),
+        None if has_default || T::validate(&JsValue::UNDEFINED, backend).is_ok() => Ok(()),
+        None => Err(Mismatch::custom(format_args!(
+            "missing field `{}`",
+            keys[0]
+        ))),
+    }
+}
+
+/// Checks that `js` is an array, for a sequence.
+pub fn array(js: &JsValue, expected: &str) -> Result<Array, Mismatch> {
+    match js.dyn_ref::<Array>() {
+        Some(array) => Ok(array.clone()),
+        None => Err(Mismatch::invalid_type(js, expected)),
+    }
+}
+
+/// Checks that `js` is an array of exactly `len` elements, for a tuple.
+pub fn tuple(js: &JsValue, len: u32, expected: &str) -> Result<Array, Mismatch> {
+    let array = array(js, expected)?;
+    if array.length() == len {
+        Ok(array)
+    } else {
+        Err(Mismatch::custom(format_args!(
+            "invalid length {}, expected {expected}",
+            array.length()
+        )))
+    }
+}
+
+/// Checks the element `index` of an array already checked by [`tuple`].
+pub fn element<T: Validate + ?Sized>(
+    array: &Array,
+    index: u32,
+    backend: Backend,
+) -> Result<(), Mismatch> {
+    T::validate(&array.get(index), backend).map_err(|m| m.at_index(index))
+}
+
+/// The array `js` is, if any, for a struct that serde can also read from an array of its `len`
+/// fields, the first `required` of which have no default.
+pub fn struct_array(
+    js: &JsValue,
+    required: u32,
+    len: u32,
+    expected: &str,
+) -> Result<Option<Array>, Mismatch> {
+    let Some(array) = js.dyn_ref::<Array>() else {
+        return Ok(None);
+    };
+
+    if (required..=len).contains(&array.length()) {
+        Ok(Some(array.clone()))
+    } else {
+        Err(Mismatch::custom(format_args!(
+            "invalid length {}, expected {expected}",
+            array.length()
+        )))
+    }
+}
+
+/// Checks the element `index` of an array from [`struct_array`], which may end before it where
+/// the rest of the fields have defaults.
+pub fn struct_element<T: Validate + ?Sized>(
+    array: &Array,
+    index: u32,
+    backend: Backend,
+) -> Result<(), Mismatch> {
+    if index < array.length() {
+        element::<T>(array, index, backend)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks a flattened field against the properties of `object` that none of the `siblings`
+/// claims, which is what serde hands to it.
+pub fn flatten<T: Validate + ?Sized>(
+    object: &JsValue,
+    siblings: &[&str],
+    backend: Backend,
+) -> Result<(), Mismatch> {
+    let rest = Object::new();
+    for entry in Object::entries(object.unchecked_ref::<Object>()).iter() {
+        let entry = entry.unchecked_into::<Array>();
+        let key = entry.get(0);
+        if !siblings.iter().any(|sibling| key == *sibling) {
+            // Cannot fail, `rest` is an ordinary extensible object.
+            let _ = Reflect::set(&rest, &key, &entry.get(1));
+        }
+    }
+    T::validate(&rest, backend)
+}
+
+/// Checks that every property of `object` is one of `known`, for `#[serde(deny_unknown_fields)]`.
+/// `JSON.stringify` leaves out properties that are `undefined`, so serde_json never sees them.
+pub fn deny_unknown_fields(
+    object: &JsValue,
+    known: &[&str],
+    backend: Backend,
+) -> Result<(), Mismatch> {
+    for entry in Object::entries(object.unchecked_ref::<Object>()).iter() {
+        let entry = entry.unchecked_into::<Array>();
+        let key = entry.get(0).as_string().unwrap_or_default();
+        if known.contains(&key.as_str()) || (!backend.is_js() && entry.get(1).is_undefined()) {
+            continue;
+        }
+
+        let expected = match known {
+            [] => "there are no fields".to_string(),
+            [name] => format!("expected `{name}`"),
+            [a, b] => format!("expected `{a}` or `{b}`"),
+            names => format!(
+                "expected one of {}",
+                names
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        return Err(Mismatch::custom(format_args!(
+            "unknown field `{key}`, {expected}"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `js` is `null` or `undefined`, for a unit struct or variant.
+pub fn unit(js: &JsValue, expected: &str) -> Result<(), Mismatch> {
+    if js.is_null() || js.is_undefined() {
+        Ok(())
+    } else {
+        Err(Mismatch::invalid_type(js, expected))
+    }
+}
+
+/// Splits an externally tagged enum into the variant name and its content: either a string
+/// naming a unit variant, or an object with a single property.
+pub fn external_tag(js: &JsValue, expected: &str) -> Result<(String, JsValue), Mismatch> {
+    if let Some(variant) = js.as_string() {
+        return Ok((variant, JsValue::UNDEFINED));
+    }
+
+    let object = object(js, expected)?;
+    let keys = Object::keys(object.unchecked_ref::<Object>());
+    match keys.get(0).as_string() {
+        Some(variant) if keys.length() == 1 => {
+            let content = property(object, &variant);
+            Ok((variant, content))
+        }
+        _ => Err(Mismatch::invalid_type(js, expected)),
+    }
+}
+
+/// Reads the variant name of an internally or adjacently tagged enum from the property `tag`.
+pub fn tag(object: &JsValue, tag: &str) -> Result<String, Mismatch> {
+    let value = property(object, tag);
+    if value.is_undefined() {
+        return Err(Mismatch::custom(format_args!("missing field `{tag}`")));
+    }
+    value
+        .as_string()
+        .ok_or_else(|| Mismatch::invalid_type(&value, "variant identifier").at_key(tag))
+}
+
+/// A variant name that matches none of `expected`.
+pub fn unknown_variant(variant: &str, expected: &[&str]) -> Mismatch {
+    let expected = expected
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Mismatch::custom(format_args!(
+        "unknown variant `{variant}`, expected one of {expected}"
+    ))
+}
+
+/// Reads the property `key` of an object, `undefined` if it is missing.
+pub fn property(object: &JsValue, key: &str) -> JsValue {
+    // Only fails if `object` is not an object, which the callers have checked.
+    Reflect::get(object, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+impl Validate for bool {
+    fn validate(js: &JsValue, _backend: Backend) -> Result<(), Mismatch> {
+        match js.as_bool() {
+            Some(_) => Ok(()),
+            None => Err(Mismatch::invalid_type(js, "a boolean")),
+        }
+    }
+}
+
+macro_rules! impl_validate_int {
+    ($($ty:ty),*) => {$(
+        impl Validate for $ty {
+            fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+                let valid = if js.is_bigint() {
+                    // `JSON.stringify` throws on a `BigInt`, and serde-wasm-bindgen only reads
+                    // 64 and 128-bit integers from one.
+                    backend.is_js() && <$ty>::BITS >= 64 && bigint_fits::<$ty>(js)
+                } else {
+                    // serde-wasm-bindgen reads integers only from safe integers, and 128-bit ones
+                    // not from numbers at all.
+                    let js_reads = !backend.is_js()
+                        || (<$ty>::BITS < 128 && js_sys::Number::is_safe_integer(js));
+                    // `MAX as f64` rounds up to a power of two from 64 bits on, which is out of
+                    // range, hence the exclusive bound `MAX + 1`. `JSON.stringify` writes numbers
+                    // from 1e21 on with an exponent, which serde_json reads as a float.
+                    js_reads
+                        && js.as_f64().is_some_and(|n| {
+                            n.fract() == 0.0
+                                && n >= <$ty>::MIN as f64
+                                && n < <$ty>::MAX as f64 + 1.0
+                                && (backend.is_js() || n.abs() < 1e21)
+                        })
+                };
+
+                match valid {
+                    true => Ok(()),
+                    false => Err(Mismatch::invalid_type(js, stringify!($ty))),
+                }
+            }
+        }
+    )*};
+}
+
+/// Whether the `BigInt` `js` is in the range of `T`.
+fn bigint_fits<T: TryFrom<i128> + TryFrom<u128>>(js: &JsValue) -> bool {
+    match i128::try_from(js.clone()) {
+        Ok(n) => T::try_from(n).is_ok(),
+        Err(js) => u128::try_from(js).is_ok_and(|n| T::try_from(n).is_ok()),
+    }
+}
+
+impl_validate_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_validate_float {
+    ($($ty:ty),*) => {$(
+        impl Validate for $ty {
+            fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+                match js.as_f64() {
+                    // `JSON.stringify` writes `NaN` and the infinities as `null`, which only an
+                    // `Option` reads.
+                    Some(_) if backend.is_js() || !is_written_as_null(js) => Ok(()),
+                    _ => Err(Mismatch::invalid_type(js, stringify!($ty))),
+                }
+            }
+        }
+    )*};
+}
+
+impl_validate_float!(f32, f64);
+
+impl Validate for char {
+    fn validate(js: &JsValue, _backend: Backend) -> Result<(), Mismatch> {
+        match js.as_string() {
+            Some(s) if s.chars().count() == 1 => Ok(()),
+            _ => Err(Mismatch::invalid_type(js, "a character")),
+        }
+    }
+}
+
+impl Validate for str {
+    fn validate(js: &JsValue, _backend: Backend) -> Result<(), Mismatch> {
+        match js.is_string() {
+            true => Ok(()),
+            false => Err(Mismatch::invalid_type(js, "a string")),
+        }
+    }
+}
+
+impl Validate for String {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        str::validate(js, backend)
+    }
+}
+
+impl Validate for () {
+    fn validate(js: &JsValue, _backend: Backend) -> Result<(), Mismatch> {
+        unit(js, "unit")
+    }
+}
+
+impl<T: ?Sized> Validate for PhantomData<T> {
+    fn validate(js: &JsValue, _backend: Backend) -> Result<(), Mismatch> {
+        unit(js, "unit")
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        if js.is_null() || js.is_undefined() || (!backend.is_js() && is_written_as_null(js)) {
+            Ok(())
+        } else {
+            T::validate(js, backend)
+        }
+    }
+}
+
+/// Whether `JSON.stringify` writes `js` as `null`, as it does `NaN` and the infinities.
+fn is_written_as_null(js: &JsValue) -> bool {
+    js.as_f64().is_some_and(|n| !n.is_finite())
+}
+
+macro_rules! impl_validate_deref {
+    ($($ty:ty),*) => {$(
+        impl<T: Validate + ?Sized> Validate for $ty {
+            fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+                T::validate(js, backend)
+            }
+        }
+    )*};
+}
+
+impl_validate_deref!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: Validate> Validate for Cell<T> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        T::validate(js, backend)
+    }
+}
+
+impl<T: Validate> Validate for RefCell<T> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        T::validate(js, backend)
+    }
+}
+
+impl<T: Validate + ToOwned + ?Sized> Validate for Cow<'_, T> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        T::validate(js, backend)
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        let array = array(js, "a sequence")?;
+        (0..array.length()).try_for_each(|index| element::<T>(&array, index, backend))
+    }
+}
+
+macro_rules! impl_validate_seq {
+    ($($ty:ty),*) => {$(
+        impl<T: Validate> Validate for $ty {
+            fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+                <[T]>::validate(js, backend)
+            }
+        }
+    )*};
+}
+
+impl_validate_seq!(Vec<T>, VecDeque<T>, LinkedList<T>, BTreeSet<T>);
+
+impl<T: Validate, S> Validate for HashSet<T, S> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        <[T]>::validate(js, backend)
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        let array = tuple(js, N as u32, &format!("an array of length {N}"))?;
+        (0..array.length()).try_for_each(|index| element::<T>(&array, index, backend))
+    }
+}
+
+/// Checks a `Map`, or an object with a string for each key.
+fn validate_map<K: Validate, V: Validate>(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+    if let Some(map) = js.dyn_ref::<Map>() {
+        // `JSON.stringify` turns any `Map` into `{}`, an empty map.
+        if !backend.is_js() {
+            return Ok(());
+        }
+
+        let mut result = Ok(());
+        map.for_each(&mut |value, key| {
+            if result.is_ok() {
+                let at_key = |m: Mismatch| m.at_key(&format!("{}", Key(&key)));
+                result = K::validate(&key, backend)
+                    .and_then(|()| V::validate(&value, backend))
+                    .map_err(at_key);
+            }
+        });
+        return result;
+    }
+
+    let object = object(js, "a map")?;
+    Object::entries(object.unchecked_ref::<Object>())
+        .iter()
+        .try_for_each(|entry| {
+            let entry = entry.unchecked_into::<Array>();
+            let key = entry.get(0).as_string().unwrap_or_default();
+            object_key::<K>(&key, backend)
+                .and_then(|()| V::validate(&entry.get(1), backend))
+                .map_err(|m| m.at_key(&key))
+        })
+}
+
+/// Checks the key of an object as `K`. serde_json also reads numbers from the string, as
+/// it writes them there.
+fn object_key<K: Validate>(key: &str, backend: Backend) -> Result<(), Mismatch> {
+    let result = K::validate(&JsValue::from_str(key), backend);
+    if result.is_err() && !backend.is_js() {
+        if let Ok(n) = key.parse::<f64>() {
+            if K::validate(&JsValue::from_f64(n), backend).is_ok() {
+                return Ok(());
+            }
+        }
+    }
+    result
+}
+
+/// A map key as it appears in a path.
+struct Key<'a>(&'a JsValue);
+
+impl fmt::Display for Key<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.as_string() {
+            Some(key) => f.write_str(&key),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+impl<K: Validate, V: Validate, S> Validate for HashMap<K, V, S> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        validate_map::<K, V>(js, backend)
+    }
+}
+
+impl<K: Validate, V: Validate> Validate for BTreeMap<K, V> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        validate_map::<K, V>(js, backend)
+    }
+}
+
+impl<T: Validate, E: Validate> Validate for Result<T, E> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        let (variant, content) = external_tag(js, "enum Result")?;
+        match variant.as_str() {
+            "Ok" => T::validate(&content, backend).map_err(|m| m.at_key("Ok")),
+            "Err" => E::validate(&content, backend).map_err(|m| m.at_key("Err")),
+            _ => Err(unknown_variant(&variant, &["Ok", "Err"])),
+        }
+    }
+}
+
+macro_rules! impl_validate_tuple {
+    ($len:literal => $($elem:ident $index:literal),*) => {
+        impl<$($elem: Validate),*> Validate for ($($elem,)*) {
+            fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+                let array = tuple(js, $len, concat!("a tuple of size ", $len))?;
+                $(element::<$elem>(&array, $index, backend)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_validate_tuple!(1 => A 0);
+impl_validate_tuple!(2 => A 0, B 1);
+impl_validate_tuple!(3 => A 0, B 1, C 2);
+impl_validate_tuple!(4 => A 0, B 1, C 2, D 3);
+impl_validate_tuple!(5 => A 0, B 1, C 2, D 3, E 4);
+impl_validate_tuple!(6 => A 0, B 1, C 2, D 3, E 4, F 5);
+impl_validate_tuple!(7 => A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_validate_tuple!(8 => A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+impl<T: Tsify + Validate, C> Validate for crate::Ts<T, C> {
+    fn validate(js: &JsValue, backend: Backend) -> Result<(), Mismatch> {
+        T::validate(js, backend)
+    }
+}
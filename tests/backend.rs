@@ -0,0 +1,83 @@
+#![cfg(all(feature = "json", feature = "js"))]
+
+use std::collections::HashMap;
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use tsify::{Backend, Ts, Tsify};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(backend = "json")]
+struct Json {
+    a: Option<u32>,
+    b: HashMap<String, u128>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(backend = "js")]
+struct Js {
+    a: Option<u32>,
+    b: HashMap<String, u128>,
+}
+
+#[test]
+fn test_backend() {
+    assert_eq!(Json::SERIALIZATION_CONFIG.backend, Backend::Json);
+    assert_eq!(
+        Json::DECL,
+        indoc! {"
+            export interface Json {
+                a: number | null;
+                b: Record<string, number>;
+            }"
+        }
+    );
+
+    assert_eq!(Js::SERIALIZATION_CONFIG.backend, Backend::Js);
+    assert_eq!(
+        Js::DECL,
+        indoc! {"
+            export interface Js {
+                a: number | undefined;
+                b: Map<string, bigint>;
+            }"
+        }
+    );
+
+    // A container is serialized with the backend of its parts. Those typed the same under
+    // either backend, like `string`, go along with the others.
+    assert_eq!(Vec::<Json>::SERIALIZATION_CONFIG.backend, Backend::Json);
+    assert_eq!(
+        <(Json, String)>::SERIALIZATION_CONFIG.backend,
+        Backend::Json
+    );
+    assert_eq!(<(String, Js)>::SERIALIZATION_CONFIG.backend, Backend::Js);
+    assert_eq!(<(Js, u128)>::SERIALIZATION_CONFIG.backend, Backend::Js);
+}
+
+#[wasm_bindgen_test]
+fn test_backend_round_trip() {
+    let json = Json {
+        a: None,
+        b: HashMap::from([("one".to_string(), 1)]),
+    };
+    let ts = json.into_ts().unwrap();
+    assert!(!field(&ts.js_value(), "b").is_instance_of::<js_sys::Map>());
+    assert!(field(&ts.js_value(), "a").is_null());
+    assert_eq!(ts.to_rust().unwrap(), json);
+
+    let js = Js {
+        a: None,
+        b: HashMap::from([("one".to_string(), 1)]),
+    };
+    let ts: Ts<Js> = js.into_ts().unwrap();
+    assert!(field(&ts.js_value(), "b").is_instance_of::<js_sys::Map>());
+    assert_eq!(ts.to_rust().unwrap(), js);
+}
+
+fn field(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &key.into()).unwrap()
+}
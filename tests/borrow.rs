@@ -0,0 +1,84 @@
+#[cfg(feature = "json")]
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use tsify::{BorrowBuffer, Ts, Tsify};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[cfg(feature = "json")]
+#[derive(Debug, PartialEq, Deserialize, Tsify)]
+#[tsify(backend = "json")]
+struct Borrow<'a> {
+    raw: &'a str,
+    #[serde(borrow)]
+    cow: Cow<'a, str>,
+    list: Vec<&'a str>,
+}
+
+#[cfg(feature = "js")]
+#[derive(Debug, PartialEq, Deserialize, Tsify)]
+#[tsify(backend = "js")]
+struct JsBorrow<'a> {
+    raw: &'a str,
+    note: Option<&'a str>,
+    count: u64,
+    list: Vec<&'a str>,
+}
+
+#[cfg(feature = "json")]
+fn parse(json: &str) -> Ts<Borrow<'static>> {
+    Ts::new_unchecked(js_sys::JSON::parse(json).unwrap())
+}
+
+#[cfg(feature = "json")]
+#[wasm_bindgen_test]
+fn test_to_rust_borrowed() {
+    let ts = parse(r#"{ "raw": "a", "cow": "b\nc", "list": ["d", "e"] }"#);
+    let mut buffer = BorrowBuffer::new();
+    let value: Borrow<'_> = ts.to_rust_borrowed(&mut buffer).unwrap();
+
+    assert_eq!(value.raw, "a");
+    assert!(matches!(value.cow, Cow::Owned(ref cow) if cow == "b\nc"));
+    assert_eq!(value.list, ["d", "e"]);
+}
+
+#[cfg(feature = "json")]
+#[wasm_bindgen_test]
+fn test_to_rust_borrowed_error() {
+    let ts = parse(r#"{ "raw": "a", "cow": "b", "list": ["d", 1] }"#);
+    let mut buffer = BorrowBuffer::new();
+    let err = ts.to_rust_borrowed::<Borrow<'_>>(&mut buffer).unwrap_err();
+
+    assert!(err.is_deserialize());
+    assert_eq!(err.path(), "list[1]");
+}
+
+#[cfg(feature = "js")]
+#[wasm_bindgen_test]
+fn test_to_rust_borrowed_js_backend() {
+    // A `BigInt` and a missing property, which JSON text could not carry, and an escaped string,
+    // which it could not lend.
+    let js = js_sys::JSON::parse(r#"{ "raw": "a\nb", "list": ["c", "d"] }"#).unwrap();
+    js_sys::Reflect::set(&js, &"count".into(), &js_sys::BigInt::from(7u64).into()).unwrap();
+    let ts: Ts<JsBorrow<'static>> = Ts::new_unchecked(js);
+
+    let mut buffer = BorrowBuffer::new();
+    let value: JsBorrow<'_> = ts.to_rust_borrowed(&mut buffer).unwrap();
+    assert_eq!(
+        value,
+        JsBorrow {
+            raw: "a\nb",
+            note: None,
+            count: 7,
+            list: vec!["c", "d"],
+        }
+    );
+    drop(value);
+
+    js_sys::Reflect::set(&ts.js_value(), &"count".into(), &"seven".into()).unwrap();
+    let err = ts
+        .to_rust_borrowed::<JsBorrow<'_>>(&mut buffer)
+        .unwrap_err();
+    assert!(err.is_deserialize());
+    assert_eq!(err.path(), "count");
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code)]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tsify::Tsify;
+
+#[test]
+fn test_brand() {
+    /// Comment for UserId
+    #[derive(Tsify)]
+    #[tsify(brand)]
+    struct UserId(String);
+
+    #[derive(Tsify)]
+    #[tsify(brand = "__kind")]
+    struct OrderId(u64);
+
+    #[derive(Tsify)]
+    #[tsify(brand)]
+    #[serde(transparent)]
+    struct Score {
+        value: f64,
+    }
+
+    assert_eq!(
+        UserId::DECL,
+        indoc! {r#"
+            /**
+             * Comment for UserId
+             */
+            export type UserId = string & { readonly __brand: "UserId" };"#
+        }
+    );
+    assert_eq!(
+        OrderId::DECL,
+        r#"export type OrderId = number & { readonly __kind: "OrderId" };"#
+    );
+    assert_eq!(
+        Score::DECL,
+        r#"export type Score = number & { readonly __brand: "Score" };"#
+    );
+}
+
+#[test]
+fn test_brand_optional() {
+    #[derive(Tsify)]
+    #[tsify(brand)]
+    struct MaybeId(Option<String>);
+
+    let expected = if cfg!(feature = "js") {
+        r#"export type MaybeId = (string | undefined) & { readonly __brand: "MaybeId" };"#
+    } else {
+        r#"export type MaybeId = (string | null) & { readonly __brand: "MaybeId" };"#
+    };
+
+    assert_eq!(MaybeId::DECL, expected);
+}
+
+#[test]
+fn test_brand_with_affixes() {
+    #[derive(Tsify)]
+    #[tsify(brand, type_prefix = "Api")]
+    struct UserId(String);
+
+    assert_eq!(
+        UserId::DECL,
+        r#"export type ApiUserId = string & { readonly __brand: "ApiUserId" };"#
+    );
+}
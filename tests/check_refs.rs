@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tsify::Tsify;
+
+#[derive(Tsify)]
+struct Address {
+    street: String,
+}
+
+#[derive(Tsify)]
+struct Wrapper<T>(T);
+
+#[test]
+fn test_check_refs() {
+    #[derive(Tsify)]
+    #[tsify(check_refs)]
+    struct Person<T> {
+        home: Address,
+        previous: Vec<Address>,
+        tagged: Wrapper<i32>,
+        generic: Wrapper<T>,
+        extra: T,
+    }
+
+    assert_eq!(
+        Person::<()>::DECL,
+        indoc! {"
+            export interface Person<T> {
+                home: Address;
+                previous: Address[];
+                tagged: Wrapper<number>;
+                generic: Wrapper<T>;
+                extra: T;
+            }"
+        }
+    );
+}
+
+#[test]
+fn test_check_refs_enum() {
+    #[derive(Tsify)]
+    #[tsify(check_refs)]
+    enum Location {
+        Known(Address),
+        Unknown,
+    }
+
+    assert_eq!(
+        Location::DECL,
+        r#"export type Location = { Known: Address } | "Unknown";"#
+    );
+}
@@ -210,3 +210,219 @@ fn test_multiple_doc_lines_through_macro() {
         }
     );
 }
+
+#[test]
+#[allow(deprecated)]
+fn test_jsdoc_tags() {
+    /// Comment for Tagged
+    #[derive(Tsify)]
+    #[deprecated(since = "1.2.0", note = "use `Renamed` instead")]
+    #[doc(alias = "Labelled")]
+    struct Tagged {
+        /// Comment for a
+        #[deprecated]
+        a: i32,
+        #[deprecated = "no longer read"]
+        #[doc(alias("bee", "buzz"))]
+        b: i32,
+        #[serde(default)]
+        c: Vec<String>,
+        #[serde(default)]
+        d: bool,
+        #[serde(default = "forty_two")]
+        e: i32,
+        #[doc(hidden)]
+        f: i32,
+    }
+
+    fn forty_two() -> i32 {
+        42
+    }
+
+    assert_eq!(
+        Tagged::DECL,
+        indoc! {r#"
+            /**
+             * Comment for Tagged
+             * @deprecated use `Renamed` instead
+             * @see Labelled
+             */
+            export interface Tagged {
+                /**
+                 * Comment for a
+                 * @deprecated
+                 */
+                a: number;
+                /**
+                 * @deprecated no longer read
+                 * @see bee
+                 * @see buzz
+                 */
+                b: number;
+                /**
+                 * @default []
+                 */
+                c?: string[];
+                /**
+                 * @default false
+                 */
+                d?: boolean;
+                e?: number;
+                f: number;
+            }"#
+        }
+    );
+}
+
+#[test]
+fn test_default_tag_only_for_empty_sequences() {
+    #[derive(Tsify)]
+    #[tsify(readonly)]
+    struct Defaults {
+        #[serde(default)]
+        a: Vec<u8>,
+        #[serde(default)]
+        b: [u8; 32],
+        #[serde(default)]
+        c: (u8, bool),
+    }
+
+    assert_eq!(
+        Defaults::DECL,
+        indoc! {r#"
+            export interface Defaults {
+                /**
+                 * @default []
+                 */
+                readonly a?: readonly number[];
+                readonly b?: readonly number[];
+                readonly c?: readonly [number, boolean];
+            }"#
+        }
+    );
+}
+
+#[test]
+fn test_deprecated_variant() {
+    #[derive(Tsify)]
+    #[tsify(namespace)]
+    enum Shape {
+        #[deprecated(note = "use `Polygon`")]
+        Square,
+        Polygon,
+    }
+
+    assert_eq!(
+        Shape::DECL,
+        indoc! {r#"
+            declare namespace Shape {
+                /**
+                 * @deprecated use `Polygon`
+                 */
+                export type Square = "Square";
+                export type Polygon = "Polygon";
+            }
+
+            export type Shape = Shape.Square | Shape.Polygon;"#
+        }
+    );
+}
+
+#[test]
+fn test_intra_doc_links() {
+    #[derive(Tsify)]
+    struct Target {
+        field: i32,
+    }
+
+    /// See [`Target`], [`crate::Target::field`] and [the docs](Target).
+    /// Rust items like [`Vec`], [Iterator], [`Self::x`] and [`serde::Serialize`] become code,
+    /// and [their docs](serde::Serialize) keep their labels.
+    /// [Web links](https://example.com) and [references][Target] are left alone,
+    /// as are `[Target]` in code, markers like [TODO] and [X], and [x] checkboxes.
+    ///
+    /// [Target]: crate::Target
+    #[derive(Tsify)]
+    struct Links {
+        /// Points to [Target].
+        x: i32,
+        target: Target,
+    }
+
+    assert_eq!(
+        Links::DECL,
+        indoc! {"
+            /**
+             * See {@link Target}, {@link Target.field} and {@link Target | the docs}.
+             * Rust items like `Vec`, `Iterator`, `Self::x` and `serde::Serialize` become code,
+             * and their docs keep their labels.
+             * [Web links](https://example.com) and [references][Target] are left alone,
+             * as are `[Target]` in code, markers like [TODO] and [X], and [x] checkboxes.
+             *
+             * [Target]: crate::Target
+             */
+            export interface Links {
+                /**
+                 * Points to {@link Target}.
+                 */
+                x: number;
+                target: Target;
+            }"
+        }
+    );
+}
+
+#[test]
+fn test_strip_rust_docs() {
+    /// Does a thing.
+    ///
+    /// ```rust
+    /// let x = Stripped { x: 1 };
+    /// ```
+    ///
+    /// ```ts
+    /// const x: Stripped = { x: 1 };
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If [`Target`] is empty.
+    ///
+    /// ```
+    /// # hidden line
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// Use it with [`Target`].
+    ///
+    /// # Safety
+    ///
+    /// Don't.
+    #[derive(Tsify)]
+    #[tsify(strip_rust_docs)]
+    struct Stripped {
+        x: i32,
+    }
+
+    assert_eq!(
+        Stripped::DECL,
+        indoc! {"
+            /**
+             * Does a thing.
+             *
+             *
+             * ```ts
+             * const x: Stripped = { x: 1 };
+             * ```
+             *
+             * # Examples
+             *
+             * Use it with `Target`.
+             */
+            export interface Stripped {
+                x: number;
+            }"
+        }
+    );
+}
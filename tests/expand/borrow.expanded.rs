@@ -225,10 +225,21 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface Borrow {\n    raw: string;\n    cow: string;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 6u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(66u32);
+            tsify::describe::inform(111u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(111u32);
+            tsify::describe::inform(119u32);
+        }
     }
     #[automatically_derived]
     impl<'a> WasmDescribe for Borrow<'a> {
@@ -231,10 +231,28 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface GenericStruct<T> {\n    x: T;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 13u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(83u32);
+            tsify::describe::inform(116u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(117u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(116u32);
+        }
     }
     #[automatically_derived]
     impl<T: Constraint> WasmDescribe for GenericStruct<T> {
@@ -662,10 +680,29 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export type GenericNewtype<T> = T;";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 14u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(78u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(119u32);
+            tsify::describe::inform(116u32);
+            tsify::describe::inform(121u32);
+            tsify::describe::inform(112u32);
+            tsify::describe::inform(101u32);
+        }
     }
     #[automatically_derived]
     impl<T: Constraint> WasmDescribe for GenericNewtype<T> {
@@ -1093,10 +1130,27 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface GenericAssoc<T> {\n    x: T;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 12u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(65u32);
+            tsify::describe::inform(115u32);
+            tsify::describe::inform(115u32);
+            tsify::describe::inform(111u32);
+            tsify::describe::inform(99u32);
+        }
     }
     #[automatically_derived]
     impl<T: Iterator<Item = u32>> WasmDescribe for GenericAssoc<T> {
@@ -1528,10 +1582,30 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface GenericLifetime {\n    x: string;\n    y: string;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 15u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(76u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(102u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(116u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(109u32);
+            tsify::describe::inform(101u32);
+        }
     }
     #[automatically_derived]
     impl<'a: 'b, 'b> WasmDescribe for GenericLifetime<'a, 'b> {
@@ -1959,10 +2033,27 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface GenericConst {\n    x: number;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 12u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(67u32);
+            tsify::describe::inform(111u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(115u32);
+            tsify::describe::inform(116u32);
+        }
     }
     #[automatically_derived]
     impl<const N: usize> WasmDescribe for GenericConst<N> {
@@ -231,10 +231,26 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export type GenericEnum<T, U> = \"Unit\" | { NewType: T } | { Seq: [T, U] } | { Map: { x: T; y: U } };";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 11u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(69u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(117u32);
+            tsify::describe::inform(109u32);
+        }
     }
     #[automatically_derived]
     impl<T, U> WasmDescribe for GenericEnum<T, U> {
@@ -230,10 +230,28 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export interface GenericStruct<T> {\n    x: T;\n}";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 13u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(83u32);
+            tsify::describe::inform(116u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(117u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(116u32);
+        }
     }
     #[automatically_derived]
     impl<T> WasmDescribe for GenericStruct<T> {
@@ -661,10 +679,29 @@ const _: () = {
         type JsType = JsType;
         const DECL: &'static str = "export type GenericNewtype<T> = T;";
         const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+            backend: tsify::Backend::Json,
             missing_as_null: false,
             hashmap_as_object: false,
             large_number_types_as_bigints: false,
+            bytes_as_arrays: false,
         };
+        const TYPESCRIPT_TYPE_LEN: u32 = 14u32;
+        fn describe_typescript_type() {
+            tsify::describe::inform(71u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(110u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(114u32);
+            tsify::describe::inform(105u32);
+            tsify::describe::inform(99u32);
+            tsify::describe::inform(78u32);
+            tsify::describe::inform(101u32);
+            tsify::describe::inform(119u32);
+            tsify::describe::inform(116u32);
+            tsify::describe::inform(121u32);
+            tsify::describe::inform(112u32);
+            tsify::describe::inform(101u32);
+        }
     }
     #[automatically_derived]
     impl<T> WasmDescribe for GenericNewtype<T> {
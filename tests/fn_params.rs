@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tsify::Tsify;
+
+#[test]
+fn test_bare_fn_param_names() {
+    #[derive(Tsify)]
+    struct Callbacks {
+        on_change: fn(name: String, value: i32),
+        on_close: fn(u16) -> bool,
+        on_error: Option<fn(message: String)>,
+    }
+
+    let expected = if cfg!(feature = "js") {
+        indoc! {"
+            export interface Callbacks {
+                on_change: (name: string, value: number) => void;
+                on_close: (arg0: number) => boolean;
+                on_error: ((message: string) => void) | undefined;
+            }"
+        }
+    } else {
+        indoc! {"
+            export interface Callbacks {
+                on_change: (name: string, value: number) => void;
+                on_close: (arg0: number) => boolean;
+                on_error: ((message: string) => void) | null;
+            }"
+        }
+    };
+
+    assert_eq!(Callbacks::DECL, expected);
+}
+
+#[test]
+fn test_params_attribute() {
+    #[derive(Tsify)]
+    struct Handlers {
+        #[tsify(params = "event, context")]
+        on_event: Box<dyn Fn(String, u32)>,
+        #[tsify(params = "event, context?")]
+        on_optional: Box<dyn Fn(String, Option<u32>) -> bool>,
+        #[tsify(params = "format, ...args")]
+        log: Box<dyn FnMut(String, Vec<String>)>,
+        #[tsify(optional, params = "value")]
+        on_maybe: Option<Box<dyn FnOnce(f64)>>,
+        #[tsify(params = "value")]
+        on_nullable: Option<Box<dyn Fn(f64)>>,
+    }
+
+    let null = if cfg!(feature = "js") {
+        "undefined"
+    } else {
+        "null"
+    };
+
+    assert_eq!(
+        Handlers::DECL,
+        format!(
+            indoc! {"
+                export interface Handlers {{
+                    on_event: (event: string, context: number) => void;
+                    on_optional: (event: string, context?: number) => boolean;
+                    log: (format: string, ...args: string[]) => void;
+                    on_maybe?: (value: number) => void;
+                    on_nullable: ((value: number) => void) | {};
+                }}"
+            },
+            null
+        )
+    );
+}
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use tsify::{AsObject, Ts, Tsify};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Item {
+    name: String,
+    price: f64,
+}
+
+fn default_currency() -> String {
+    "EUR".to_string()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(getters)]
+#[serde(rename_all = "camelCase")]
+struct Order {
+    order_id: u32,
+    #[serde(alias = "customer")]
+    customer_name: String,
+    note: Option<String>,
+    #[serde(default)]
+    quantity: u32,
+    #[serde(default = "default_currency")]
+    currency: String,
+    #[tsify(lazy)]
+    items: Vec<Item>,
+    #[serde(default)]
+    #[tsify(lazy)]
+    discounts: Vec<f64>,
+    tags: HashMap<String, bool>,
+    r#type: String,
+    #[serde(skip)]
+    cache: Option<Vec<u8>>,
+}
+
+/// A type from another crate, like `Uuid`, that has no `Tsify` impl.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Hex(String);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(getters)]
+struct Palette {
+    names: HashSet<String>,
+    rgba: [u8; 4],
+    base: Box<Item>,
+    hex: Option<Hex>,
+    #[tsify(lazy)]
+    weights: HashMap<String, u32>,
+}
+
+fn parse(json: &str) -> Ts<Order> {
+    Ts::new_unchecked(js_sys::JSON::parse(json).unwrap())
+}
+
+#[wasm_bindgen_test]
+fn test_scalar_getters() {
+    let order =
+        parse(r#"{ "orderId": 7, "customer": "Ada", "items": [], "tags": {}, "type": "online" }"#);
+
+    assert_eq!(order.get_order_id().unwrap(), 7);
+    assert_eq!(order.get_customer_name().unwrap(), "Ada");
+    assert_eq!(order.get_note().unwrap(), None);
+    assert_eq!(order.get_quantity().unwrap(), 0);
+    assert_eq!(order.get_currency().unwrap(), "EUR");
+    assert_eq!(order.get_type().unwrap(), "online");
+}
+
+#[wasm_bindgen_test]
+fn test_nested_getters() {
+    let order = parse(
+        r#"{
+            "orderId": 7,
+            "customerName": "Ada",
+            "items": [{ "name": "a", "price": 1.5 }],
+            "tags": { "gift": true },
+            "type": "online"
+        }"#,
+    );
+
+    let items: Ts<Vec<Item>> = order.get_items();
+    assert_eq!(
+        items.to_rust().unwrap(),
+        vec![Item {
+            name: "a".to_string(),
+            price: 1.5,
+        }]
+    );
+    assert_eq!(
+        order.get_tags().unwrap(),
+        HashMap::from([("gift".to_string(), true)])
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_defaulted_nested_getters() {
+    let order = parse(r#"{ "orderId": 7, "items": [], "tags": {}, "type": "online" }"#);
+    assert!(order.get_discounts().is_none());
+
+    let order =
+        parse(r#"{ "orderId": 7, "items": [], "discounts": [0.5], "tags": {}, "type": "online" }"#);
+    assert_eq!(order.get_discounts().unwrap().to_rust().unwrap(), vec![0.5]);
+}
+
+#[wasm_bindgen_test]
+fn test_getter_errors() {
+    let order = parse(r#"{ "orderId": "7", "items": [], "tags": {}, "type": "online" }"#);
+
+    let err = order.get_order_id().unwrap_err();
+    assert!(err.is_deserialize());
+    assert_eq!(err.path(), "orderId");
+    if cfg!(not(feature = "omit-type-name")) {
+        assert_eq!(err.type_name(), Some("getters::Order"));
+    }
+
+    let err = order.get_customer_name().unwrap_err();
+    assert_eq!(err.path(), "");
+    assert!(err.to_string().contains("missing field `customerName`"));
+}
+
+#[wasm_bindgen_test]
+fn test_getters_without_tsify_impl() {
+    let palette = Ts::<Palette>::new_unchecked(
+        js_sys::JSON::parse(
+            r#"{
+                "names": ["red"],
+                "rgba": [1, 2, 3, 4],
+                "base": { "name": "a", "price": 1.5 },
+                "hex": "fff",
+                "weights": {}
+            }"#,
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(
+        palette.get_names().unwrap(),
+        HashSet::from(["red".to_string()])
+    );
+    assert_eq!(palette.get_rgba().unwrap(), [1, 2, 3, 4]);
+    assert_eq!(palette.get_base().unwrap().price, 1.5);
+    assert_eq!(palette.get_hex().unwrap(), Some(Hex("fff".to_string())));
+}
+
+#[wasm_bindgen_test]
+fn test_getters_keep_config() {
+    let palette = Palette {
+        names: HashSet::new(),
+        rgba: [0; 4],
+        base: Box::new(Item {
+            name: "a".to_string(),
+            price: 1.5,
+        }),
+        hex: None,
+        weights: HashMap::from([("a".to_string(), 1)]),
+    };
+    let palette = Ts::<Palette, AsObject>::from_rust(&palette).unwrap();
+
+    let weights: Ts<HashMap<String, u32>, AsObject> = palette.get_weights();
+    assert!(!weights.js_value().is_instance_of::<js_sys::Map>());
+    assert_eq!(weights.to_rust().unwrap()["a"], 1);
+}
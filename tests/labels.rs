@@ -0,0 +1,114 @@
+#![allow(dead_code)]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tsify::Tsify;
+
+#[test]
+fn test_container_labels() {
+    #[derive(Tsify)]
+    #[tsify(labels = "x, y")]
+    struct Point(f64, f64);
+
+    assert_eq!(Point::DECL, "export type Point = [x: number, y: number];");
+}
+
+#[test]
+fn test_field_labels() {
+    #[derive(Tsify)]
+    struct Range(#[tsify(label = "start")] u32, #[tsify(label = "end")] u32);
+
+    #[derive(Tsify)]
+    #[tsify(labels = "a, b")]
+    struct Overridden(i32, #[tsify(label = "second")] String);
+
+    assert_eq!(
+        Range::DECL,
+        "export type Range = [start: number, end: number];"
+    );
+    assert_eq!(
+        Overridden::DECL,
+        "export type Overridden = [a: number, second: string];"
+    );
+}
+
+#[test]
+fn test_label_comments() {
+    /// Comment for Point
+    #[derive(Tsify)]
+    #[tsify(labels = "x, y")]
+    struct Point(
+        /// The horizontal position
+        f64,
+        /// The vertical position
+        f64,
+    );
+
+    assert_eq!(
+        Point::DECL,
+        indoc! {"
+            /**
+             * Comment for Point
+             */
+            export type Point = [
+                /**
+                 * The horizontal position
+                 */
+                x: number,
+                /**
+                 * The vertical position
+                 */
+                y: number,
+            ];"
+        }
+    );
+}
+
+#[test]
+fn test_optional_trailing_labels() {
+    #[derive(Tsify)]
+    #[tsify(labels = "x, y, z")]
+    struct Point(f64, #[serde(default)] f64, #[serde(default)] f64);
+
+    assert_eq!(
+        Point::DECL,
+        indoc! {"
+            export type Point = [
+                x: number,
+                /**
+                 * @default 0
+                 */
+                y?: number,
+                /**
+                 * @default 0
+                 */
+                z?: number,
+            ];"
+        }
+    );
+}
+
+#[test]
+fn test_labelled_variant() {
+    #[derive(Tsify)]
+    enum Shape {
+        Line(#[tsify(label = "from")] f64, #[tsify(label = "to")] f64),
+    }
+
+    assert_eq!(
+        Shape::DECL,
+        r#"export type Shape = { Line: [from: number, to: number] };"#
+    );
+}
+
+#[test]
+fn test_readonly_labels() {
+    #[derive(Tsify)]
+    #[tsify(readonly, labels = "x, ys")]
+    struct Point(f64, Vec<f64>);
+
+    assert_eq!(
+        Point::DECL,
+        "export type Point = readonly [x: number, ys: readonly number[]];"
+    );
+}
@@ -53,6 +53,7 @@ fn test_optional() {
                 b?: string;
                 /**
                  * Comment for c
+                 * @default 0
                  */
                 c?: number;
                 /**
@@ -102,6 +103,7 @@ fn test_optional() {
                     b?: string;
                     /**
                      * Comment for c
+                     * @default 0
                      */
                     c?: number;
                     /**
@@ -52,5 +52,57 @@ fn test_transparent() {
                 a: bigint;
             }"
         }
-    )
+    );
+
+    // Stands in for `serde_bytes::ByteBuf`, which tsify recognises by name.
+    struct ByteBuf(Vec<u8>);
+
+    #[derive(Tsify)]
+    struct Bytes {
+        a: ByteBuf,
+    }
+
+    assert_eq!(
+        Bytes::DECL,
+        indoc! {"
+            export interface Bytes {
+                a: Uint8Array;
+            }"
+        }
+    );
+
+    #[derive(Tsify)]
+    #[tsify(bytes_as_arrays)]
+    struct ByteArray {
+        a: ByteBuf,
+    }
+
+    assert_eq!(
+        ByteArray::DECL,
+        indoc! {"
+            export interface ByteArray {
+                a: number[];
+            }"
+        }
+    );
+
+    #[derive(Tsify)]
+    #[tsify(json_compatible)]
+    struct JsonCompatible {
+        a: Option<u32>,
+        b: HashMap<String, u32>,
+        c: ByteBuf,
+    }
+
+    assert_eq!(
+        JsonCompatible::DECL,
+        indoc! {"
+            export interface JsonCompatible {
+                a: number | null;
+                b: Record<string, number>;
+                c: number[];
+            }"
+        }
+    );
+    const _: () = assert!(JsonCompatible::SERIALIZATION_CONFIG.bytes_as_arrays);
 }
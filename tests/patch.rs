@@ -0,0 +1,262 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde::{
+    de::{
+        value::{Error, MapDeserializer},
+        IntoDeserializer,
+    },
+    Deserialize, Serialize,
+};
+use tsify::{ApplyPatch, AsObject, Ts, Tsify};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(getters, setters, patch)]
+#[serde(rename_all = "camelCase")]
+struct State {
+    /// Number of clicks so far.
+    click_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected: Option<String>,
+    items: Vec<String>,
+    #[serde(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(setters, patch)]
+struct Scores {
+    by_name: HashMap<String, u32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(patch = "PairUpdate")]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(partial = "ProfileChanges")]
+struct Profile {
+    /// Shown next to the avatar.
+    #[serde(rename = "displayName")]
+    name: String,
+    age: u32,
+}
+
+#[test]
+fn test_patch_decl() {
+    assert_eq!(
+        StatePatch::DECL,
+        indoc! {"
+            /**
+             * A patch of `State`: the fields to change, each one optional.
+             */
+            export interface StatePatch {
+                /**
+                 * Number of clicks so far.
+                 */
+                clickCount?: number;
+                selected?: string | null;
+                items?: string[];
+            }"
+        }
+    );
+
+    let patch = StatePatch::default();
+    assert_eq!(patch.click_count, None);
+
+    assert_eq!(
+        PairUpdate::<u32>::DECL,
+        indoc! {"
+            /**
+             * A patch of `Pair`: the fields to change, each one optional.
+             */
+            export interface PairUpdate<T> {
+                first?: T;
+                second?: T;
+            }"
+        }
+    );
+}
+
+#[test]
+fn test_patch_clears_optional_field() {
+    fn patch<V: IntoDeserializer<'static, Error>>(entries: Vec<(&'static str, V)>) -> StatePatch {
+        StatePatch::deserialize(MapDeserializer::new(entries.into_iter())).unwrap()
+    }
+
+    let mut state = State {
+        click_count: 1,
+        selected: Some("a".to_string()),
+        items: vec![],
+        cache: vec![],
+    };
+
+    // A missing field is left as it is.
+    state.apply(patch::<()>(vec![]));
+    assert_eq!(state.selected.as_deref(), Some("a"));
+
+    // A `null`, which serde reads as a unit, clears it.
+    let clear = patch(vec![("selected", ())]);
+    assert_eq!(clear.selected, Some(None));
+    state.apply(clear);
+    assert_eq!(state.selected, None);
+
+    state.apply(StatePatch {
+        selected: Some(Some("b".to_string())),
+        ..Default::default()
+    });
+    assert_eq!(state.selected.as_deref(), Some("b"));
+}
+
+#[test]
+fn test_partial() {
+    assert_eq!(
+        ProfileChanges::DECL,
+        indoc! {"
+            /**
+             * A patch of `Profile`: the fields to change, each one optional.
+             */
+            export interface ProfileChanges {
+                /**
+                 * Shown next to the avatar.
+                 */
+                displayName?: string;
+                age?: number;
+            }"
+        }
+    );
+
+    let mut profile = Profile {
+        name: "Ada".to_string(),
+        age: 36,
+    };
+    profile.apply(ProfileChanges {
+        name: Some("Grace".to_string()),
+        ..Default::default()
+    });
+    assert_eq!(
+        profile,
+        Profile {
+            name: "Grace".to_string(),
+            age: 36,
+        }
+    );
+
+    profile.apply(ProfileChanges::default());
+    assert_eq!(profile.age, 36);
+
+    // The inherent `apply` forwards to the trait, which generic code goes through.
+    fn apply<T: ApplyPatch>(value: &mut T, patch: T::Patch) {
+        ApplyPatch::apply(value, patch);
+    }
+    apply(
+        &mut profile,
+        ProfileChanges {
+            age: Some(37),
+            ..Default::default()
+        },
+    );
+    assert_eq!(profile.age, 37);
+}
+
+fn state() -> Ts<State> {
+    State {
+        click_count: 1,
+        selected: Some("a".to_string()),
+        items: vec!["a".to_string()],
+        cache: vec![],
+    }
+    .into_ts()
+    .unwrap()
+}
+
+fn get(ts: &Ts<State>, key: &str) -> JsValue {
+    js_sys::Reflect::get(&ts.js_value(), &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_setters() {
+    let ts = state();
+    let before = ts.js_value();
+
+    ts.set_click_count(&2).unwrap();
+    assert_eq!(ts.get_click_count().unwrap(), 2);
+    assert_eq!(get(&ts, "clickCount"), 2);
+
+    ts.set_selected(&None).unwrap();
+    assert!(!js_sys::Reflect::has(&ts.js_value(), &"selected".into()).unwrap());
+
+    // Still the same object.
+    assert!(js_sys::Object::is(&before, &ts.js_value()));
+}
+
+#[wasm_bindgen_test]
+fn test_setters_on_frozen_object() {
+    let ts = state();
+    js_sys::Object::freeze(ts.js_value().unchecked_ref::<js_sys::Object>());
+
+    let err = ts.set_click_count(&2).unwrap_err();
+    assert!(err.is_serialize());
+    assert_eq!(err.path(), "clickCount");
+    assert_eq!(get(&ts, "clickCount"), 1);
+
+    let err = ts.set_selected(&None).unwrap_err();
+    assert_eq!(err.path(), "selected");
+    assert_eq!(get(&ts, "selected"), "a");
+
+    let err = ts
+        .apply_patch(&StatePatch {
+            click_count: Some(5),
+            ..Default::default()
+        })
+        .unwrap_err();
+    assert_eq!(err.path(), "clickCount");
+}
+
+#[wasm_bindgen_test]
+fn test_setters_use_config() {
+    let ts: Ts<Scores, AsObject> = Ts::new_unchecked(js_sys::Object::new().into());
+    let scores = HashMap::from([("a".to_string(), 1)]);
+    let by_name = || js_sys::Reflect::get(&ts.js_value(), &"by_name".into()).unwrap();
+
+    ts.set_by_name(&scores).unwrap();
+    assert!(!by_name().is_instance_of::<js_sys::Map>());
+
+    ts.apply_patch(&ScoresPatch {
+        by_name: Some(scores),
+    })
+    .unwrap();
+    assert!(!by_name().is_instance_of::<js_sys::Map>());
+}
+
+#[wasm_bindgen_test]
+fn test_apply_patch() {
+    let ts = state();
+    let before = ts.js_value();
+
+    ts.apply_patch(&StatePatch {
+        click_count: Some(5),
+        items: Some(vec!["b".to_string(), "c".to_string()]),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert!(js_sys::Object::is(&before, &ts.js_value()));
+    assert_eq!(
+        ts.to_rust().unwrap(),
+        State {
+            click_count: 5,
+            selected: Some("a".to_string()),
+            items: vec!["b".to_string(), "c".to_string()],
+            cache: vec![],
+        }
+    );
+}
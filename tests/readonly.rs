@@ -0,0 +1,106 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tsify::Tsify;
+
+#[test]
+fn test_readonly_container() {
+    /// Comment for Store
+    #[derive(Tsify)]
+    #[tsify(readonly)]
+    struct Store {
+        /// Comment for items
+        items: Vec<String>,
+        pair: (i32, Option<Vec<bool>>),
+        lookup: HashMap<String, Vec<i32>>,
+        grid: Vec<Vec<u8>>,
+    }
+
+    let expected = if cfg!(feature = "js") {
+        indoc! {"
+            /**
+             * Comment for Store
+             */
+            export interface Store {
+                /**
+                 * Comment for items
+                 */
+                readonly items: readonly string[];
+                readonly pair: readonly [number, readonly boolean[] | undefined];
+                readonly lookup: Map<string, readonly number[]>;
+                readonly grid: readonly (readonly number[])[];
+            }"
+        }
+    } else {
+        indoc! {"
+            /**
+             * Comment for Store
+             */
+            export interface Store {
+                /**
+                 * Comment for items
+                 */
+                readonly items: readonly string[];
+                readonly pair: readonly [number, readonly boolean[] | null];
+                readonly lookup: Record<string, readonly number[]>;
+                readonly grid: readonly (readonly number[])[];
+            }"
+        }
+    };
+
+    assert_eq!(Store::DECL, expected);
+}
+
+#[test]
+fn test_readonly_field() {
+    #[derive(Tsify)]
+    struct Partial {
+        #[tsify(readonly)]
+        id: u32,
+        #[tsify(readonly, optional)]
+        tags: Option<Vec<String>>,
+        scratch: Vec<u8>,
+    }
+
+    let expected = indoc! {"
+        export interface Partial {
+            readonly id: number;
+            readonly tags?: readonly string[];
+            scratch: number[];
+        }"
+    };
+
+    assert_eq!(Partial::DECL, expected);
+}
+
+#[test]
+fn test_readonly_tuple_struct() {
+    #[derive(Tsify)]
+    #[tsify(readonly)]
+    struct Point(f64, f64);
+
+    assert_eq!(
+        Point::DECL,
+        "export type Point = readonly [number, number];"
+    );
+}
+
+#[test]
+fn test_readonly_enum() {
+    #[derive(Tsify)]
+    #[tsify(readonly)]
+    enum Shape {
+        Polygon(Vec<(f64, f64)>),
+        Circle { radius: f64 },
+        Empty,
+    }
+
+    let expected = indoc! {r#"
+        export type Shape = { readonly Polygon: readonly (readonly [number, number])[] } | { readonly Circle: { readonly radius: number } } | "Empty";"#
+    };
+
+    assert_eq!(Shape::DECL, expected);
+}
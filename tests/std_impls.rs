@@ -0,0 +1,166 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use tsify::{AsObject, SerializationConfig, Ts, TsConfig, Tsify};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+// The Typescript type is informed to wasm-bindgen one character at a time, with its length
+// ahead of it, so both have to match.
+macro_rules! assert_ts_type {
+    ($ty:ty, $expected:expr) => {
+        assert_eq!(tsify::describe::typescript_type::<$ty>(), $expected);
+        assert_eq!(
+            <$ty as Tsify>::TYPESCRIPT_TYPE_LEN as usize,
+            $expected.len(),
+            "{}",
+            $expected
+        );
+    };
+}
+
+#[test]
+fn test_typescript_type() {
+    assert_ts_type!(bool, "boolean");
+    assert_ts_type!(String, "string");
+    assert_ts_type!(f64, "number");
+    assert_ts_type!(Vec<Point>, "Point[]");
+    assert_ts_type!(Box<[Vec<u8>]>, "number[][]");
+    assert_ts_type!((Point, String), "[Point, string]");
+    assert_ts_type!((u8,), "[number]");
+    assert_ts_type!((bool, Vec<String>, Point), "[boolean, string[], Point]");
+
+    if cfg!(feature = "js") {
+        assert_ts_type!(u128, "bigint");
+        assert_ts_type!(i128, "bigint");
+        assert_ts_type!(Option<Point>, "Point | undefined");
+        assert_ts_type!(Vec<Option<u8>>, "(number | undefined)[]");
+        assert_ts_type!(Vec<Option<Point>>, "(Point | undefined)[]");
+        assert_ts_type!(HashMap<String, Point>, "Map<string, Point>");
+        assert_ts_type!(BTreeMap<u32, bool>, "Map<number, boolean>");
+        assert_ts_type!((u128, Option<u64>), "[bigint, number | undefined]");
+    } else {
+        assert_ts_type!(u128, "number");
+        assert_ts_type!(i128, "number");
+        assert_ts_type!(Option<Point>, "Point | null");
+        assert_ts_type!(Vec<Option<u8>>, "(number | null)[]");
+        assert_ts_type!(Vec<Option<Point>>, "(Point | null)[]");
+        assert_ts_type!(HashMap<String, Point>, "Record<string, Point>");
+        assert_ts_type!(BTreeMap<u32, bool>, "Record<number, boolean>");
+        assert_ts_type!((u128, Option<u64>), "[number, number | null]");
+    }
+}
+
+#[cfg(feature = "js")]
+#[test]
+fn test_typescript_type_config() {
+    #[derive(Serialize, Deserialize, Tsify)]
+    #[tsify(missing_as_null, hashmap_as_object)]
+    struct Config {
+        name: String,
+    }
+
+    // The element type decides how the container is serialized.
+    assert_ts_type!(Option<Config>, "Config | null");
+    assert_ts_type!(BTreeMap<String, Config>, "Record<string, Config>");
+    // Parts whose type does not depend on the config go along with it.
+    assert_ts_type!((Config, String, u32), "[Config, string, number]");
+    assert_eq!(
+        <(String, Config)>::SERIALIZATION_CONFIG,
+        Config::SERIALIZATION_CONFIG
+    );
+
+    // A nested `Ts` keeps the type of its config.
+    assert_ts_type!(Vec<Ts<Point>>, "Point[]");
+    assert_ts_type!(
+        Vec<Ts<Option<Point>, AsObject>>,
+        "TsifyAsObject<Point | undefined>[]"
+    );
+    assert_ts_type!(
+        Ts<HashMap<String, Point>, AsObject>,
+        "TsifyAsObject<Map<string, Point>>"
+    );
+}
+
+#[test]
+fn test_as_object_config() {
+    let config = AsObject::config(SerializationConfig::default());
+    assert_eq!(
+        config,
+        SerializationConfig {
+            hashmap_as_object: true,
+            ..SerializationConfig::default()
+        }
+    );
+}
+
+#[wasm_bindgen(inline_js = r#"
+function points() {
+    return [{ x: 1, y: 2 }, { x: 3, y: 4 }];
+}
+
+function lengthOf(points) {
+    return points.length;
+}
+
+module.exports = { points, lengthOf };
+"#)]
+extern "C" {
+    fn points() -> Ts<Vec<Point>>;
+    #[wasm_bindgen(js_name = "lengthOf")]
+    fn length_of(points: &Ts<Vec<Point>>) -> usize;
+}
+
+#[wasm_bindgen_test]
+fn test_vec() {
+    let points = points().to_rust().unwrap();
+    assert_eq!(points, vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+
+    let ts = points.into_ts().unwrap();
+    assert_eq!(length_of(&ts), 2);
+}
+
+#[wasm_bindgen_test]
+fn test_option() {
+    let ts = None::<Point>.into_ts().unwrap();
+    assert_eq!(ts.to_rust().unwrap(), None);
+
+    let ts = Some(Point { x: 1, y: 2 }).into_ts().unwrap();
+    assert_eq!(ts.to_rust().unwrap(), Some(Point { x: 1, y: 2 }));
+}
+
+#[wasm_bindgen_test]
+fn test_map() {
+    let map = HashMap::from([("origin".to_string(), Point { x: 0, y: 0 })]);
+    let ts = map.into_ts().unwrap();
+    assert_eq!(ts.to_rust().unwrap(), map);
+}
+
+#[wasm_bindgen_test]
+fn test_tuple() {
+    let tuple = (Point { x: 1, y: 2 }, "label".to_string(), 3u8);
+    let ts = tuple.into_ts().unwrap();
+    assert_eq!(ts.to_rust().unwrap(), tuple);
+}
+
+#[wasm_bindgen_test]
+fn test_as_object() {
+    let map = HashMap::from([("origin".to_string(), Point { x: 0, y: 0 })]);
+
+    let ts = Ts::<_, AsObject>::from_rust(&map).unwrap();
+    assert!(!ts.js_value().is_instance_of::<js_sys::Map>());
+    assert_eq!(ts.to_rust().unwrap(), map);
+
+    let config = SerializationConfig {
+        hashmap_as_object: true,
+        ..SerializationConfig::default()
+    };
+    let ts = Ts::<HashMap<String, Point>>::from_rust_with(&map, &config).unwrap();
+    assert!(!ts.js_value().is_instance_of::<js_sys::Map>());
+}
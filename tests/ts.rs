@@ -166,6 +166,7 @@ fn test_data_that_cant_be_serialized_throws_an_appropriate_error() {
 // No point testing Vec<Ts<CantBeSerialized>> here, since you call the same
 // CantBeSerialized::into_ts to build such a thing.
 
+#[cfg(not(feature = "omit-type-name"))]
 #[wasm_bindgen_test]
 fn error_includes_type_name_ser() {
     let val = CantBeSerialized { value: 42 };
@@ -175,6 +176,7 @@ fn error_includes_type_name_ser() {
     assert!(err_msg.contains("serialize type `ts::CantBeSerialized`"));
 }
 
+#[cfg(not(feature = "omit-type-name"))]
 #[wasm_bindgen_test]
 fn error_includes_type_name_de() {
     let val: Ts<CantBeSerialized> = Ts::new_unchecked(JsValue::NULL);
@@ -184,3 +186,102 @@ fn error_includes_type_name_de() {
     assert!(err_msg.contains("deserialize"));
     assert!(err_msg.contains("`ts::CantBeSerialized`"));
 }
+
+#[wasm_bindgen_test]
+fn error_accessors() {
+    let err = CantBeSerialized { value: 42 }.into_ts().unwrap_err();
+    assert!(err.is_serialize());
+    assert!(!err.is_deserialize());
+    assert!(err.inner().to_string().contains("NO_SERIALIZE"));
+
+    let val: Ts<CantBeSerialized> = Ts::new_unchecked(JsValue::NULL);
+    let err = val.to_rust().unwrap_err();
+    assert!(err.is_deserialize());
+
+    if cfg!(feature = "omit-type-name") {
+        assert_eq!(err.type_name(), None);
+    } else {
+        assert_eq!(err.type_name(), Some("ts::CantBeSerialized"));
+    }
+}
+
+#[wasm_bindgen_test]
+fn error_includes_path_de() {
+    #[derive(Debug, Deserialize, Tsify)]
+    struct Order {
+        #[allow(dead_code)]
+        items: Vec<SimpleData>,
+    }
+
+    let js = js_sys::JSON::parse(
+        r#"{ "items": [{ "value": 1, "text": "a" }, { "value": 2, "text": 3 }] }"#,
+    )
+    .unwrap();
+    let val: Ts<Order> = Ts::new_unchecked(js);
+    let err = val.to_rust().unwrap_err();
+    assert_eq!(err.path(), "items[1].text");
+    assert!(err.to_string().contains("at `items[1].text`"));
+
+    let val: Ts<Order> = Ts::new_unchecked(JsValue::from(5));
+    let err = val.to_rust().unwrap_err();
+    assert_eq!(err.path(), "");
+}
+
+#[wasm_bindgen_test]
+fn error_into_js_value() {
+    let val: Ts<SimpleData> =
+        Ts::new_unchecked(js_sys::JSON::parse(r#"{ "value": "x" }"#).unwrap());
+    let js = JsValue::from(val.to_rust().unwrap_err());
+
+    let get = |key: &str| js_sys::Reflect::get(&js, &key.into()).unwrap();
+    assert!(js.is_instance_of::<js_sys::Error>());
+    assert_eq!(get("name"), "TsifyError");
+
+    let class = js_sys::Reflect::get(&js_sys::global(), &"TsifyError".into()).unwrap();
+    let prototype = js_sys::Reflect::get(&class, &"prototype".into()).unwrap();
+    assert_eq!(
+        JsValue::from(js_sys::Object::get_prototype_of(&js)),
+        prototype
+    );
+    if cfg!(not(feature = "omit-type-name")) {
+        assert_eq!(get("typeName"), "ts::SimpleData");
+    }
+    assert_eq!(get("direction"), "deserialize");
+    assert_eq!(get("path"), "value");
+    assert!(get("message").as_string().unwrap().contains("at `value`"));
+    assert!(get("cause").is_instance_of::<js_sys::Error>());
+}
+
+/// Crosses into JS as degrees Fahrenheit, through its own `from_js_tracked` and `into_js`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Celsius(f64);
+
+impl Tsify for Celsius {
+    type JsType = tsify::JsTypeOf<Self>;
+    const DECL: &'static str = "";
+
+    fn from_js_tracked(js: JsValue) -> Result<Self, tsify::Error> {
+        let fahrenheit = js.as_f64().unwrap_or_default();
+        Ok(Celsius((fahrenheit - 32.0) * 5.0 / 9.0))
+    }
+
+    fn into_js(&self) -> Result<Self::JsType, tsify::SerializationError> {
+        Ok(JsValue::from(self.0 * 9.0 / 5.0 + 32.0).unchecked_into())
+    }
+}
+
+#[wasm_bindgen_test]
+fn manual_from_js_is_used() {
+    let val: Ts<Celsius> = Ts::new_unchecked(JsValue::from(212.0));
+    assert_eq!(val.to_rust().unwrap(), Celsius(100.0));
+    assert_eq!(Celsius::from_js(212.0).unwrap(), Celsius(100.0));
+}
+
+#[wasm_bindgen_test]
+fn manual_into_js_is_used() {
+    let val = Ts::<Celsius>::from_rust(&Celsius(100.0)).unwrap();
+    assert_eq!(JsValue::from(val), 212.0);
+
+    let val = Celsius(0.0).into_ts().unwrap();
+    assert_eq!(JsValue::from(val), 32.0);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "js")]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use tsify::{AsObject, Ts, Tsify};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Meta {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Payload {
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(getters)]
+struct Job {
+    name: String,
+    meta: Ts<Meta>,
+    payload: Ts<Payload>,
+    extra: Option<Ts<Meta, AsObject>>,
+}
+
+#[test]
+fn test_ts_field_decl() {
+    assert_eq!(
+        Job::DECL,
+        indoc! {"
+            export interface Job {
+                name: string;
+                meta: Meta;
+                payload: Payload;
+                extra: TsifyAsObject<Meta> | undefined;
+            }"
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_ts_field_round_trip() {
+    let js = js_sys::JSON::parse(
+        r#"{ "name": "a", "meta": { "id": 1 }, "payload": { "data": [1, 2] }, "extra": null }"#,
+    )
+    .unwrap();
+    let meta = js_sys::Reflect::get(&js, &"meta".into()).unwrap();
+
+    let job: Job = Ts::<Job>::new_unchecked(js).to_rust().unwrap();
+    assert_eq!(job.name, "a");
+    assert!(JsValue::from(job.meta.clone()) == meta);
+    assert_eq!(job.meta.to_rust().unwrap(), Meta { id: 1 });
+
+    let js = JsValue::from(job.into_ts().unwrap());
+    assert!(js_sys::Reflect::get(&js, &"meta".into()).unwrap() == meta);
+}
+
+#[wasm_bindgen_test]
+fn test_ts_field_getter() {
+    let js = js_sys::JSON::parse(r#"{ "name": "a", "meta": { "id": 1 }, "payload": {} }"#).unwrap();
+    let job: Ts<Job> = Ts::new_unchecked(js);
+    let meta: Ts<Meta> = job.get_meta();
+    assert_eq!(meta.to_rust().unwrap(), Meta { id: 1 });
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use tsify::{TsFn, Tsify};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Offset {
+    dx: i32,
+    dy: i32,
+}
+
+#[wasm_bindgen(inline_js = r#"
+function translate() {
+    return (point, offset) => ({ x: point.x + offset.dx, y: point.y + offset.dy });
+}
+
+function origin() {
+    return () => ({ x: 0, y: 0 });
+}
+
+function broken() {
+    return () => "not a point";
+}
+
+function throwing() {
+    return () => {
+        throw new Error("CALLBACK_FAILED");
+    };
+}
+
+module.exports = { translate, origin, broken, throwing };
+"#)]
+extern "C" {
+    fn translate() -> TsFn<(Point, Offset), Point>;
+    fn origin() -> TsFn<(), Point>;
+    fn broken() -> TsFn<(), Point>;
+    fn throwing() -> TsFn<()>;
+}
+
+#[wasm_bindgen_test]
+fn test_call() {
+    let point = translate()
+        .call((Point { x: 1, y: 2 }, Offset { dx: 10, dy: 20 }))
+        .unwrap();
+    assert_eq!(point, Point { x: 11, y: 22 });
+
+    assert_eq!(origin().call(()).unwrap(), Point { x: 0, y: 0 });
+}
+
+#[wasm_bindgen_test]
+fn test_call_return_type_mismatch() {
+    let err = broken().call(()).unwrap_err();
+    let err_msg = err.to_string();
+    assert!(err_msg.contains("deserialize"));
+    if cfg!(not(feature = "omit-type-name")) {
+        assert!(err_msg.contains("`ts_fn::Point`"));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_call_exception() {
+    let err = throwing().call(()).unwrap_err();
+    let err_msg = err.to_string();
+    assert!(err_msg.contains("threw"));
+    assert!(err_msg.contains("CALLBACK_FAILED"));
+}
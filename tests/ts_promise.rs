@@ -0,0 +1,61 @@
+#![cfg(feature = "futures")]
+
+use serde::{Deserialize, Serialize};
+use tsify::{TsPromise, Tsify};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Tsify)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[wasm_bindgen(inline_js = r#"
+function resolved() {
+    return Promise.resolve({ x: 1, y: 2 });
+}
+
+function rejected() {
+    return Promise.reject(new Error("PROMISE_REJECTED"));
+}
+
+function roundTrip(promise) {
+    return promise;
+}
+
+module.exports = { resolved, rejected, roundTrip };
+"#)]
+extern "C" {
+    fn resolved() -> TsPromise<Point>;
+    fn rejected() -> TsPromise<Point>;
+    #[wasm_bindgen(js_name = "roundTrip")]
+    fn round_trip(promise: TsPromise<Point>) -> TsPromise<Point>;
+}
+
+#[wasm_bindgen_test]
+async fn test_await() {
+    assert_eq!(resolved().to_rust().await.unwrap(), Point { x: 1, y: 2 });
+}
+
+#[wasm_bindgen_test]
+async fn test_rejected() {
+    let err = rejected().to_rust().await.unwrap_err();
+    let err_msg = err.to_string();
+    assert!(err_msg.contains("rejected"));
+    assert!(err_msg.contains("PROMISE_REJECTED"));
+}
+
+#[wasm_bindgen_test]
+async fn test_from_future() {
+    let promise = TsPromise::from_future(async { Point { x: 3, y: 4 } });
+    let point = round_trip(promise).to_rust().await.unwrap();
+    assert_eq!(point, Point { x: 3, y: 4 });
+}
+
+#[wasm_bindgen_test]
+async fn test_from_try_future() {
+    let promise = TsPromise::<Point>::from_try_future(async { Err(JsError::new("FAILED")) });
+    let err = round_trip(promise).to_rust().await.unwrap_err();
+    assert!(err.to_string().contains("FAILED"));
+}
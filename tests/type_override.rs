@@ -175,3 +175,36 @@ fn test_generic_struct_with_field_param_override() {
 
     assert_eq!(Foo::<Bar>::DECL, expected);
 }
+
+#[test]
+fn test_struct_with_serde_with_and_type_override() {
+    mod as_secs {
+        use serde::{Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(_: &super::Unsupported, s: S) -> Result<S::Ok, S::Error> {
+            s.serialize_u64(0)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            _: D,
+        ) -> Result<super::Unsupported, D::Error> {
+            Ok(super::Unsupported)
+        }
+    }
+
+    #[derive(Tsify, serde::Serialize, serde::Deserialize)]
+    #[tsify(strict)]
+    struct Struct {
+        #[serde(with = "as_secs")]
+        #[tsify(type = "number")]
+        elapsed: Unsupported,
+    }
+
+    let expected = indoc! {r#"
+        export interface Struct {
+            elapsed: number;
+        }"#
+    };
+
+    assert_eq!(Struct::DECL, expected);
+}
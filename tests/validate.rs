@@ -0,0 +1,233 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tsify::{Ts, Tsify, Validate};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+#[serde(rename_all = "camelCase")]
+struct Item {
+    name: String,
+    unit_price: f64,
+    #[serde(default)]
+    quantity: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+struct Order {
+    id: u64,
+    items: Vec<Item>,
+    note: Option<String>,
+    tags: HashMap<String, bool>,
+    status: Status,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+#[serde(tag = "kind")]
+enum Status {
+    Open,
+    Shipped { tracking: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+#[serde(untagged)]
+enum NumberOrPair {
+    Number(i32),
+    Pair(i32, i32),
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+struct Labelled {
+    id: u32,
+    #[serde(flatten)]
+    labels: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+#[serde(deny_unknown_fields)]
+struct Strict {
+    #[serde(alias = "identifier")]
+    id: u32,
+    #[serde(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Tsify)]
+#[tsify(validate)]
+#[serde(tag = "kind", deny_unknown_fields)]
+enum StrictEvent {
+    Click { x: i32, y: i32 },
+}
+
+fn parse<T: Tsify>(json: &str) -> Ts<T> {
+    Ts::new_unchecked(js_sys::JSON::parse(json).unwrap())
+}
+
+#[test]
+fn test_validate_impls() {
+    fn assert_validate<T: Validate>() {}
+
+    assert_validate::<Order>();
+    assert_validate::<Tree<Shape>>();
+    assert_validate::<Vec<Option<(NumberOrPair, String)>>>();
+}
+
+#[wasm_bindgen_test]
+fn test_validate_struct() {
+    let order: Ts<Order> = parse(
+        r#"{
+            "id": 1,
+            "items": [{ "name": "a", "unitPrice": 1.5 }, { "name": "b", "unitPrice": 2, "quantity": 3 }],
+            "tags": { "gift": true },
+            "status": { "kind": "Shipped", "tracking": "x" }
+        }"#,
+    );
+    order.validate().unwrap();
+    order.to_rust().unwrap();
+
+    let order: Ts<Order> = parse(
+        r#"{
+            "id": 1,
+            "items": [{ "name": "a", "unitPrice": 1.5 }, { "name": "b", "unitPrice": "2" }],
+            "tags": {},
+            "status": { "kind": "Open" }
+        }"#,
+    );
+    let err = order.validate().unwrap_err();
+    assert!(err.is_deserialize());
+    assert_eq!(err.path(), "items[1].unitPrice");
+    assert!(err
+        .to_string()
+        .contains(r#"invalid type: string "2", expected f64"#));
+
+    let order: Ts<Order> = parse(r#"{ "id": 1, "items": [], "tags": {} }"#);
+    let err = order.validate().unwrap_err();
+    assert_eq!(err.path(), "");
+    assert!(err.to_string().contains("missing field `status`"));
+
+    let order: Ts<Order> = Ts::new_unchecked(JsValue::from(5));
+    assert!(order.validate().is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_validate_enum() {
+    parse::<Shape>(r#""Point""#).validate().unwrap();
+    parse::<Shape>(r#"{ "Circle": 1 }"#).validate().unwrap();
+    parse::<Shape>(r#"{ "Rect": { "w": 1, "h": 2 } }"#)
+        .validate()
+        .unwrap();
+
+    let err = parse::<Shape>(r#"{ "Rect": { "w": 1 } }"#)
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.path(), "Rect");
+    assert!(err.to_string().contains("missing field `h`"));
+
+    let err = parse::<Shape>(r#""Square""#).validate().unwrap_err();
+    assert!(err.to_string().contains("unknown variant `Square`"));
+
+    let err = parse::<Status>(r#"{ "kind": "Shipped" }"#)
+        .validate()
+        .unwrap_err();
+    assert!(err.to_string().contains("missing field `tracking`"));
+
+    parse::<NumberOrPair>("1").validate().unwrap();
+    parse::<NumberOrPair>("[1, 2]").validate().unwrap();
+    let err = parse::<NumberOrPair>("[1, 2, 3]").validate().unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("data did not match any variant of untagged enum NumberOrPair"));
+}
+
+#[wasm_bindgen_test]
+fn test_validate_generic() {
+    let tree: Ts<Tree<u8>> = parse(
+        r#"{ "value": 1, "children": [{ "value": 2, "children": [] }, { "value": 300, "children": [] }] }"#,
+    );
+    let err = tree.validate().unwrap_err();
+    assert_eq!(err.path(), "children[1].value");
+}
+
+#[wasm_bindgen_test]
+fn test_validate_flatten() {
+    parse::<Labelled>(r#"{ "id": 1, "color": "red" }"#)
+        .validate()
+        .unwrap();
+
+    let err = parse::<Labelled>(r#"{ "id": 1, "size": 2 }"#)
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.path(), "size");
+}
+
+#[wasm_bindgen_test]
+fn test_validate_integer_bounds() {
+    parse::<u32>("4294967295").validate().unwrap();
+    assert!(parse::<u32>("4294967296").validate().is_err());
+    assert!(parse::<u64>("18446744073709551616").validate().is_err());
+    assert!(parse::<i64>("9223372036854775808").validate().is_err());
+    assert!(parse::<i64>("-9223372036854775809").validate().is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_validate_map_keys() {
+    let err = parse::<HashMap<bool, u8>>(r#"{ "yes": 1 }"#)
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.path(), "yes");
+
+    // serde_json reads numbers from object keys, serde-wasm-bindgen does not.
+    let numeric = parse::<HashMap<u32, u8>>(r#"{ "1": 1 }"#).validate();
+    let backend = <HashMap<u32, u8> as Tsify>::SERIALIZATION_CONFIG.backend;
+    assert_eq!(numeric.is_ok(), !backend.is_js());
+}
+
+#[wasm_bindgen_test]
+fn test_validate_deny_unknown_fields() {
+    parse::<Strict>(r#"{ "id": 1 }"#).validate().unwrap();
+    parse::<Strict>(r#"{ "identifier": 1 }"#)
+        .validate()
+        .unwrap();
+
+    for json in [
+        r#"{ "id": 1, "extra": true }"#,
+        r#"{ "id": 1, "cache": [] }"#,
+    ] {
+        let strict = parse::<Strict>(json);
+        let err = strict.validate().unwrap_err();
+        assert!(err.to_string().contains("unknown field"), "{err}");
+        assert!(strict.to_rust().is_err());
+    }
+
+    parse::<StrictEvent>(r#"{ "kind": "Click", "x": 1, "y": 2 }"#)
+        .validate()
+        .unwrap();
+    let err = parse::<StrictEvent>(r#"{ "kind": "Click", "x": 1, "y": 2, "z": 3 }"#)
+        .validate()
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown field `z`"), "{err}");
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde::{de::value::MapDeserializer, Deserialize, Serialize};
+use tsify::Tsify;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(
+    view(name = "UserSummary", fields = "id, display_name"),
+    view(name = "UserContact", fields = "display_name, email, type")
+)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: u64,
+    /// The name shown to other users.
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub r#type: String,
+    pub password_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Tsify)]
+#[tsify(view(name = "ThemeSettings", omit = "volume"))]
+#[serde(default = "Settings::guest")]
+pub struct Settings {
+    pub theme: String,
+    pub volume: u32,
+}
+
+impl Settings {
+    fn guest() -> Self {
+        Self {
+            theme: "dark".to_string(),
+            volume: 5,
+        }
+    }
+}
+
+fn user() -> User {
+    User {
+        id: 7,
+        display_name: "Ada".to_string(),
+        email: None,
+        r#type: "admin".to_string(),
+        password_hash: "secret".to_string(),
+    }
+}
+
+#[test]
+fn test_view_decl() {
+    assert_eq!(
+        UserSummary::DECL,
+        indoc! {"
+            /**
+             * A view of `User` with only `id`, `displayName`.
+             */
+            export interface UserSummary {
+                id: number;
+                /**
+                 * The name shown to other users.
+                 */
+                displayName: string;
+            }"
+        }
+    );
+
+    assert_eq!(
+        UserContact::DECL,
+        indoc! {"
+            /**
+             * A view of `User` with only `displayName`, `email`, `type`.
+             */
+            export interface UserContact {
+                /**
+                 * The name shown to other users.
+                 */
+                displayName: string;
+                email?: string;
+                /**
+                 * @default \"\"
+                 */
+                type?: string;
+            }"
+        }
+    );
+}
+
+#[test]
+fn test_view_omit() {
+    assert_eq!(
+        ThemeSettings::DECL,
+        indoc! {"
+            /**
+             * A view of `Settings` with only `theme`.
+             */
+            export interface ThemeSettings {
+                theme?: string;
+            }"
+        }
+    );
+
+    // A missing field takes its value from the container's default, not the field type's.
+    let empty =
+        MapDeserializer::<_, serde::de::value::Error>::new(std::iter::empty::<(&str, &str)>());
+    let view = ThemeSettings::deserialize(empty).unwrap();
+    assert_eq!(view.theme, "dark");
+}
+
+#[test]
+fn test_view_from() {
+    let user = user();
+
+    let summary = UserSummary::from(&user);
+    assert_eq!(summary.id, 7);
+    assert_eq!(summary.display_name, "Ada");
+
+    let contact = UserContact::from(&user);
+    assert_eq!(contact.email, None);
+    assert_eq!(contact.r#type, "admin");
+}
@@ -0,0 +1,218 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use serde_derive_internals::{
+    ast::{Data, Field},
+    attr,
+};
+use syn::{ext::IdentExt, spanned::Spanned};
+
+use crate::{
+    attrs::TsifyFieldAttrs, container::Container, typescript::is_tsify_path, validate::is_custom,
+};
+
+/// The `FooFields` trait for `#[tsify(getters)]` and `#[tsify(setters)]`, implemented for
+/// `Ts<Foo, C>`. Each getter reads one property and deserializes it right away, unless the field
+/// is `#[tsify(lazy)]`, which hands it back as a `Ts<_, C>` of its own so that it is only
+/// deserialized when needed. Each setter serializes a value into one property, leaving the rest
+/// of the object and its identity alone.
+pub fn expand(cont: &Container) -> TokenStream {
+    let attrs = &cont.attrs;
+    let attr = if attrs.getters { "getters" } else { "setters" };
+    let Some(fields) = named_fields(cont, attr) else {
+        return TokenStream::new();
+    };
+
+    let getters: Vec<_> = fields
+        .iter()
+        .filter(|field| {
+            attrs.getters
+                && !field.attrs.skip_deserializing()
+                && !field.attrs.flatten()
+                && !is_custom(field)
+        })
+        .map(|field| expand_getter(cont, field))
+        .collect();
+    let setters: Vec<_> = fields
+        .iter()
+        .filter(|field| attrs.setters && is_written(field))
+        .map(|field| expand_setter(cont, field))
+        .collect();
+
+    let ident = cont.ident();
+    let vis = &cont.serde_container.original.vis;
+    let name = format_ident!("{}Fields", ident);
+    let doc = format!(" Reads and writes single fields of a `Ts<{ident}>`.");
+    let signatures = getters
+        .iter()
+        .chain(&setters)
+        .map(|(signature, _)| signature);
+    let methods = getters
+        .iter()
+        .chain(&setters)
+        .map(|(signature, body)| quote!(#signature { #body }));
+
+    quote! {
+        #[doc = #doc]
+        #[allow(clippy::ptr_arg)]
+        #vis trait #name {
+            /// The config of the `Ts` the fields belong to, which the getters keep.
+            type Config: tsify::TsConfig;
+
+            #(#signatures;)*
+        }
+
+        #[automatically_derived]
+        impl<C: tsify::TsConfig> #name for tsify::Ts<#ident, C> {
+            type Config = C;
+
+            #(#methods)*
+        }
+    }
+}
+
+/// Whether `field` can be written on its own, as serde would serialize it within the container.
+pub fn is_written(field: &Field) -> bool {
+    !field.attrs.skip_serializing()
+        && !field.attrs.flatten()
+        && field.attrs.serialize_with().is_none()
+        && field.attrs.getter().is_none()
+}
+
+/// Writes `value`, a reference to a value of the type of `field`, into the property of `js` it
+/// is serialized as. Deletes the property instead if serde would skip the value.
+pub fn expand_write(
+    field: &Field,
+    js: &TokenStream,
+    value: &TokenStream,
+    config: &TokenStream,
+    container: &TokenStream,
+) -> TokenStream {
+    let key = field.attrs.name().serialize_name();
+    let set = quote!(tsify::fields::set::<#container, _>(#js, #key, #value, #config));
+
+    match field.attrs.skip_serializing_if() {
+        Some(skip) => quote! {
+            if #skip(#value) {
+                tsify::fields::remove::<#container>(#js, #key)
+            } else {
+                #set
+            }
+        },
+        None => set,
+    }
+}
+
+/// The fields of a struct with named fields, reporting `#[serde(transparent)]`, which has no
+/// property per field.
+fn named_fields<'a>(cont: &'a Container, attr: &str) -> Option<&'a [Field<'a>]> {
+    if cont.transparent() {
+        cont.syn_error(syn::Error::new_spanned(
+            cont.ident(),
+            format!("#[tsify({attr})] cannot be used with #[serde(transparent)]"),
+        ));
+        return None;
+    }
+
+    match cont.serde_data() {
+        Data::Struct(_, fields) => Some(fields),
+        Data::Enum(_) => None,
+    }
+}
+
+/// `get_foo` or `set_foo` for the field `foo`.
+fn method_ident(prefix: &str, field: &Field) -> syn::Ident {
+    match &field.member {
+        syn::Member::Named(member) => {
+            format_ident!("{prefix}_{}", member.unraw(), span = member.span())
+        }
+        syn::Member::Unnamed(_) => unreachable!("accessors require named fields"),
+    }
+}
+
+fn expand_setter(cont: &Container, field: &Field) -> (TokenStream, TokenStream) {
+    let ident = cont.ident();
+    let setter = method_ident("set", field);
+    let ty = field.ty;
+    let write = expand_write(
+        field,
+        &quote!(tsify::fields::js(self)),
+        &quote!(value),
+        &quote!(&C::config(<#ident as tsify::Tsify>::SERIALIZATION_CONFIG)),
+        &quote!(#ident),
+    );
+
+    (
+        quote!(fn #setter(&self, value: &#ty) -> ::core::result::Result<(), tsify::Error>),
+        write,
+    )
+}
+
+fn expand_getter(cont: &Container, field: &Field) -> (TokenStream, TokenStream) {
+    let ident = cont.ident();
+    let ty = field.ty;
+
+    let getter = method_ident("get", field);
+
+    let name = field.attrs.name().deserialize_name();
+    let aliases = field.attrs.aliases().iter().filter(|alias| *alias != name);
+    let keys = quote!(&[#name #(, #aliases)*]);
+
+    let property = quote! {
+        tsify::Ts::new_unchecked(tsify::fields::property(tsify::fields::js(self), #keys))
+    };
+
+    // A `Ts` field is already the JS value.
+    if matches!(ty, syn::Type::Path(p) if p.qself.is_none() && is_tsify_path(&p.path, "Ts")) {
+        return (quote!(fn #getter(&self) -> #ty), property);
+    }
+
+    // A malformed attribute is reported by the parser already.
+    let lazy = TsifyFieldAttrs::from_serde_field(field).is_ok_and(|attrs| attrs.lazy);
+    if lazy {
+        // Spanned so that a type without a `Tsify` impl is reported here.
+        let ts = quote_spanned!(ty.span()=> tsify::Ts<#ty, Self::Config>);
+        // Filling in a default would take serializing it, so a missing property is left to the
+        // caller as `None`, where `to_rust` would have used the default.
+        if field.attrs.default().is_none() && cont.serde_attrs().default().is_none() {
+            return (quote!(fn #getter(&self) -> #ts), property);
+        }
+        return (
+            quote!(fn #getter(&self) -> ::core::option::Option<#ts>),
+            quote! {
+                let value = tsify::fields::property(tsify::fields::js(self), #keys);
+                (!value.is_undefined()).then(|| tsify::Ts::new_unchecked(value))
+            },
+        );
+    }
+
+    let member = &field.member;
+    let default = match (field.attrs.default(), cont.serde_attrs().default()) {
+        (attr::Default::Default, _) => {
+            quote!(::core::option::Option::Some(<#ty as ::core::default::Default>::default))
+        }
+        (attr::Default::Path(path), _) => quote!(::core::option::Option::Some(#path)),
+        (attr::Default::None, attr::Default::Default) => quote! {
+            ::core::option::Option::Some(|| <#ident as ::core::default::Default>::default().#member)
+        },
+        (attr::Default::None, attr::Default::Path(path)) => {
+            quote!(::core::option::Option::Some(|| #path().#member))
+        }
+        (attr::Default::None, attr::Default::None) => quote!(::core::option::Option::None),
+    };
+
+    (
+        quote!(fn #getter(&self) -> ::core::result::Result<#ty, tsify::Error>),
+        quote!(tsify::fields::get::<#ident, #ty>(tsify::fields::js(self), #keys, #default)),
+    )
+}
+
+/// Whether `ty` is a path ending in `ident`, e.g. `std::option::Option<T>` for `Option`.
+pub fn last_ident_is(ty: &syn::Type, ident: &str) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == ident),
+        _ => false,
+    }
+}
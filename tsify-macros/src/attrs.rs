@@ -15,6 +15,27 @@ pub struct TsifyContainerAttrs {
 
     /// Whether the type should be wrapped in a Typescript namespace.
     pub namespace: bool,
+    /// Turn diagnostics about types tsify cannot represent into errors, and reject types that
+    /// would otherwise be emitted as `never`. Always on with the `strict` feature.
+    pub strict: bool,
+    /// Key of the brand property intersected with a newtype, e.g. `__brand`.
+    pub brand: Option<String>,
+    /// Whether every property, array and tuple of the type should be readonly.
+    pub readonly: bool,
+    /// Assert at compile time that every type referenced by the declaration implements `Tsify`.
+    pub check_refs: bool,
+    /// Implement `tsify::Validate`, checking the shape of a `JsValue` without deserializing it.
+    pub validate: bool,
+    /// Generate a getter on `Ts<T>` for each field, reading just that property.
+    pub getters: bool,
+    /// Generate a setter on `Ts<T>` for each field, writing just that property.
+    pub setters: bool,
+    /// Name of the all-optional patch type to generate for `patch` or `partial`, e.g. `FooPatch`.
+    pub patch: Option<String>,
+    /// Types with a subset of the fields to generate, one for each `view(...)`.
+    pub views: Vec<View>,
+    /// Labels for the elements of a tuple struct, e.g. `[x: number, y: number]`.
+    pub labels: Option<Vec<String>>,
     /// Information about how the type should be serialized.
     pub ty_config: TypeGenerationConfig,
 
@@ -24,9 +45,45 @@ pub struct TsifyContainerAttrs {
     pub from_wasm_abi_span: Option<Span>,
 }
 
+/// A type with some of the fields of a struct, from
+/// `#[tsify(view(name = "UserSummary", fields = "id, name"))]` or
+/// `#[tsify(view(name = "PublicUser", omit = "password_hash"))]`.
+#[derive(Debug)]
+pub struct View {
+    pub name: String,
+    pub fields: Vec<String>,
+    /// Whether `fields` are the ones to leave out, from `omit`, rather than the ones to keep.
+    pub omit: bool,
+    /// Span of the attribute, for errors about its fields.
+    pub span: Span,
+}
+
+/// Which serializer converts the type to and from JS, which also decides how Rust types map to
+/// Typescript types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// `serde_json`, through a JSON string.
+    Json,
+    /// `serde_wasm_bindgen`.
+    Js,
+}
+
+impl Default for Backend {
+    /// `js` if the feature is enabled, as tsify always did before `backend` could be chosen.
+    fn default() -> Self {
+        if cfg!(feature = "js") {
+            Self::Js
+        } else {
+            Self::Json
+        }
+    }
+}
+
 /// Configuration affecting how Typescript types are generated.
 #[derive(Debug, Default)]
 pub struct TypeGenerationConfig {
+    /// The serializer the type is converted with
+    pub backend: Backend,
     /// Universal prefix for generated types
     pub type_prefix: Option<String>,
     /// Universal suffix for generated types
@@ -37,6 +94,11 @@ pub struct TypeGenerationConfig {
     pub hashmap_as_object: bool,
     /// Whether large number types should be represented as BigInts in Typescript
     pub large_number_types_as_bigints: bool,
+    /// Whether byte buffers should be represented as `number[]` rather than `Uint8Array`
+    pub bytes_as_arrays: bool,
+    /// Whether Rust-only doc sections (`# Panics`, `# Safety`, Rust code blocks) should be
+    /// left out of the generated doc comments
+    pub strip_rust_docs: bool,
 }
 
 impl TypeGenerationConfig {
@@ -46,6 +108,11 @@ impl TypeGenerationConfig {
         let suffix = self.type_suffix.as_ref().map_or("", String::as_str);
         format!("{}{}{}", prefix, name, suffix)
     }
+
+    /// Whether the type is converted with `serde_wasm_bindgen` rather than `serde_json`.
+    pub const fn is_js(&self) -> bool {
+        matches!(self.backend, Backend::Js)
+    }
 }
 
 impl TsifyContainerAttrs {
@@ -58,8 +125,22 @@ impl TsifyContainerAttrs {
             from_wasm_abi_span: None,
             into_wasm_abi_span: None,
             namespace: false,
+            strict: false,
+            brand: None,
+            readonly: false,
+            check_refs: false,
+            validate: false,
+            getters: false,
+            setters: false,
+            patch: None,
+            views: Vec::new(),
+            labels: None,
             ty_config: TypeGenerationConfig::default(),
         };
+        let mut json_compatible = false;
+        let mut backend_set = false;
+        // The first option that needs the `js` backend, which may be chosen after it.
+        let mut js_option: Option<(Span, &str)> = None;
 
         for attr in &input.attrs {
             if !attr.path().is_ident("tsify") {
@@ -114,6 +195,158 @@ impl TsifyContainerAttrs {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("strict") {
+                    if attrs.strict {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.strict = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("brand") {
+                    if !matches!(input.data, syn::Data::Struct(_)) {
+                        return Err(meta.error("#[tsify(brand)] can only be used on newtype structs"));
+                    }
+                    if attrs.brand.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let key = if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::LitStr>()?.value()
+                    } else {
+                        "__brand".to_string()
+                    };
+                    attrs.brand = Some(key);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("readonly") {
+                    if attrs.readonly {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.readonly = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("check_refs") {
+                    if attrs.check_refs {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.check_refs = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("validate") {
+                    if attrs.validate {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.validate = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("getters") {
+                    if !matches!(&input.data, syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Named(_))) {
+                        return Err(meta.error("#[tsify(getters)] can only be used on structs with named fields"));
+                    }
+                    // The getters are a trait of the container's own, which is never generic.
+                    if !input.generics.params.is_empty() {
+                        return Err(meta.error("#[tsify(getters)] cannot be used on generic types"));
+                    }
+                    if attrs.getters {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.getters = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("setters") {
+                    if !matches!(&input.data, syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Named(_))) {
+                        return Err(meta.error("#[tsify(setters)] can only be used on structs with named fields"));
+                    }
+                    // Like the getters, these are methods of the container's own trait.
+                    if !input.generics.params.is_empty() {
+                        return Err(meta.error("#[tsify(setters)] cannot be used on generic types"));
+                    }
+                    if attrs.setters {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.setters = true;
+                    return Ok(());
+                }
+
+                // `partial` is the same type under the name Typescript gives it, `Partial<Foo>`.
+                if meta.path.is_ident("patch") || meta.path.is_ident("partial") {
+                    let attr = if meta.path.is_ident("patch") { "patch" } else { "partial" };
+                    if !matches!(&input.data, syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Named(_))) {
+                        return Err(meta.error(format!("#[tsify({attr})] can only be used on structs with named fields")));
+                    }
+                    if attrs.patch.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let name = if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::LitStr>()?.value()
+                    } else {
+                        format!("{}Patch", input.ident)
+                    };
+                    attrs.patch = Some(name);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("view") {
+                    if !matches!(&input.data, syn::Data::Struct(data) if matches!(data.fields, syn::Fields::Named(_))) {
+                        return Err(meta.error("#[tsify(view)] can only be used on structs with named fields"));
+                    }
+                    // A view keeps only some fields, which may not use every type parameter.
+                    if !input.generics.params.is_empty() {
+                        return Err(meta.error("#[tsify(view)] cannot be used on generic types"));
+                    }
+                    let span = meta.path.span();
+                    let mut name = None;
+                    let mut fields = None;
+                    let mut omit = None;
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("name") {
+                            if name.is_some() {
+                                return Err(meta.error("duplicate attribute"));
+                            }
+                            name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                            return Ok(());
+                        }
+
+                        let list = if meta.path.is_ident("fields") {
+                            &mut fields
+                        } else if meta.path.is_ident("omit") {
+                            &mut omit
+                        } else {
+                            return Err(meta.error("unsupported view attribute, expected `name`, `fields` or `omit`"));
+                        };
+                        if list.is_some() {
+                            return Err(meta.error("duplicate attribute"));
+                        }
+                        let lit = meta.value()?.parse::<syn::LitStr>()?;
+                        *list = Some(lit.value().split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect());
+                        Ok(())
+                    })?;
+                    let (name, fields, omit) = match (name, fields, omit) {
+                        (Some(name), Some(fields), None) => (name, fields, false),
+                        (Some(name), None, Some(omitted)) => (name, omitted, true),
+                        _ => return Err(meta.error("#[tsify(view)] requires `name = \"...\"` and one of `fields = \"...\"` or `omit = \"...\"`")),
+                    };
+                    attrs.views.push(View { name, fields, omit, span });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("labels") {
+                    if !matches!(input.data, syn::Data::Struct(_)) {
+                        return Err(meta.error("#[tsify(labels)] can only be used on tuple structs"));
+                    }
+                    if attrs.labels.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    attrs.labels = Some(lit.value().split(',').map(|s| s.trim().to_string()).collect());
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("type_prefix") {
                     if attrs.ty_config.type_prefix.is_some() {
                         return Err(meta.error("duplicate attribute"));
@@ -132,7 +365,77 @@ impl TsifyContainerAttrs {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("strip_rust_docs") {
+                    if attrs.ty_config.strip_rust_docs {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.ty_config.strip_rust_docs = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("backend") {
+                    if backend_set {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    let (backend, feature_enabled) = match lit.value().as_str() {
+                        "json" => (Backend::Json, cfg!(feature = "json")),
+                        "js" => (Backend::Js, cfg!(feature = "js")),
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `backend = \"json\"` or `backend = \"js\"`",
+                            ))
+                        }
+                    };
+                    if !feature_enabled {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "#[tsify(backend = \"{0}\")] requires the `{0}` feature",
+                                lit.value()
+                            ),
+                        ));
+                    }
+                    backend_set = true;
+                    attrs.ty_config.backend = backend;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("json_compatible") {
+                    if json_compatible {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    if cfg!(not(feature = "js")) {
+                        return Err(meta.error(
+                            "#[tsify(json_compatible)] requires the `js` feature",
+                        ));
+                    }
+                    js_option.get_or_insert((meta.path.span(), "json_compatible"));
+                    json_compatible = true;
+                    // The same preset as `serde_wasm_bindgen::Serializer::json_compatible()`.
+                    let config = &mut attrs.ty_config;
+                    for (name, flag) in [
+                        ("missing_as_null", &mut config.missing_as_null),
+                        ("hashmap_as_object", &mut config.hashmap_as_object),
+                        ("bytes_as_arrays", &mut config.bytes_as_arrays),
+                    ] {
+                        if *flag {
+                            return Err(meta.error(format!(
+                                "#[tsify(json_compatible)] already implies `{name}`"
+                            )));
+                        }
+                        *flag = true;
+                    }
+                    return Ok(());
+                }
+
                 if meta.path.is_ident("missing_as_null") {
+                    if json_compatible {
+                        return Err(meta.error(
+                            "#[tsify(json_compatible)] already implies `missing_as_null`",
+                        ));
+                    }
                     if attrs.ty_config.missing_as_null {
                         return Err(meta.error("duplicate attribute"));
                     }
@@ -141,11 +444,17 @@ impl TsifyContainerAttrs {
                             "#[tsify(missing_as_null)] requires the `js` feature",
                         ));
                     }
+                    js_option.get_or_insert((meta.path.span(), "missing_as_null"));
                     attrs.ty_config.missing_as_null = true;
                     return Ok(());
                 }
 
                 if meta.path.is_ident("hashmap_as_object") {
+                    if json_compatible {
+                        return Err(meta.error(
+                            "#[tsify(json_compatible)] already implies `hashmap_as_object`",
+                        ));
+                    }
                     if attrs.ty_config.hashmap_as_object {
                         return Err(meta.error("duplicate attribute"));
                     }
@@ -154,6 +463,7 @@ impl TsifyContainerAttrs {
                             "#[tsify(hashmap_as_object)] requires the `js` feature",
                         ));
                     }
+                    js_option.get_or_insert((meta.path.span(), "hashmap_as_object"));
                     attrs.ty_config.hashmap_as_object = true;
                     return Ok(());
                 }
@@ -167,14 +477,43 @@ impl TsifyContainerAttrs {
                             "#[tsify(large_number_types_as_bigints)] requires the `js` feature",
                         ));
                     }
+                    js_option.get_or_insert((meta.path.span(), "large_number_types_as_bigints"));
                     attrs.ty_config.large_number_types_as_bigints = true;
                     return Ok(());
                 }
 
-                Err(meta.error("unsupported tsify attribute, expected one of `type`, `type_params`, `into_wasm_abi`, `from_wasm_abi`, `namespace`, `type_prefix`, `type_suffix`, `missing_as_null`, `hashmap_as_object`, `large_number_types_as_bigints`"))
+                if meta.path.is_ident("bytes_as_arrays") {
+                    if json_compatible {
+                        return Err(meta.error(
+                            "#[tsify(json_compatible)] already implies `bytes_as_arrays`",
+                        ));
+                    }
+                    if attrs.ty_config.bytes_as_arrays {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    if cfg!(not(feature = "js")) {
+                        return Err(meta.error(
+                            "#[tsify(bytes_as_arrays)] requires the `js` feature",
+                        ));
+                    }
+                    js_option.get_or_insert((meta.path.span(), "bytes_as_arrays"));
+                    attrs.ty_config.bytes_as_arrays = true;
+                    return Ok(());
+                }
+
+                Err(meta.error("unsupported tsify attribute, expected one of `type`, `type_params`, `into_wasm_abi`, `from_wasm_abi`, `namespace`, `strict`, `brand`, `readonly`, `check_refs`, `validate`, `getters`, `setters`, `patch`, `partial`, `view`, `labels`, `type_prefix`, `type_suffix`, `strip_rust_docs`, `backend`, `missing_as_null`, `hashmap_as_object`, `large_number_types_as_bigints`, `bytes_as_arrays`, `json_compatible`"))
             })?;
         }
 
+        if let Some((span, name)) = js_option.filter(|_| !attrs.ty_config.is_js()) {
+            return Err(syn::Error::new(
+                span,
+                format!("#[tsify({name})] requires `backend = \"js\"`"),
+            ));
+        }
+
+        attrs.strict |= cfg!(feature = "strict");
+
         Ok(attrs)
     }
 }
@@ -184,6 +523,13 @@ pub struct TsifyFieldAttrs {
     pub type_override: Option<String>,
     pub type_params: Option<Vec<String>>,
     pub optional: bool,
+    pub readonly: bool,
+    /// Label of the element in a labelled tuple.
+    pub label: Option<String>,
+    /// Parameter names of a function type, e.g. `event, context?, ...rest`.
+    pub params: Option<Vec<String>>,
+    /// Whether the getter hands the field back as a `Ts` of its own rather than deserializing it.
+    pub lazy: bool,
 }
 
 impl TsifyFieldAttrs {
@@ -192,6 +538,10 @@ impl TsifyFieldAttrs {
             type_override: None,
             type_params: None,
             optional: false,
+            readonly: false,
+            label: None,
+            params: None,
+            lazy: false,
         };
 
         for attr in &field.original.attrs {
@@ -231,7 +581,46 @@ impl TsifyFieldAttrs {
                     return Ok(());
                 }
 
-                Err(meta.error("unsupported tsify attribute, expected one of `type`, `type_params` or `optional`"))
+                if meta.path.is_ident("readonly") {
+                    if attrs.readonly {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.readonly = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("label") {
+                    if attrs.label.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    attrs.label = Some(lit.value());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("params") {
+                    if attrs.params.is_some() {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    let lit = meta.value()?.parse::<syn::LitStr>()?;
+                    attrs.params = Some(
+                        lit.value()
+                            .split(',')
+                            .map(|s| s.trim().to_string())
+                            .collect(),
+                    );
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("lazy") {
+                    if attrs.lazy {
+                        return Err(meta.error("duplicate attribute"));
+                    }
+                    attrs.lazy = true;
+                    return Ok(());
+                }
+
+                Err(meta.error("unsupported tsify attribute, expected one of `type`, `type_params`, `optional`, `readonly`, `label`, `params` or `lazy`"))
             })?;
         }
 
@@ -250,3 +639,33 @@ impl TsifyFieldAttrs {
         Ok(attrs)
     }
 }
+
+/// The `deserialize_with` written on `field`, if any. serde also fills it in for a
+/// `#[serde(borrow)]` `Cow<'a, str>` or `Cow<'a, [u8]>`, which still deserializes like its type,
+/// and so does a patch for an optional field, with [`is_double_option`].
+pub fn custom_deserialize_with<'a>(field: &'a Field) -> Option<&'a syn::ExprPath> {
+    field.attrs.deserialize_with().filter(|expr| {
+        let segments = &expr.path.segments;
+        let borrow_cow = segments.len() == 4
+            && segments[0].ident == "_serde"
+            && matches!(
+                segments[3].ident.to_string().as_str(),
+                "borrow_cow_str" | "borrow_cow_bytes"
+            );
+        !borrow_cow && !is_double_option(field)
+    })
+}
+
+/// Whether `field` is the patch of an optional field, an `Option<Option<T>>` that reads `null`
+/// as `Some(None)`.
+pub fn is_double_option(field: &Field) -> bool {
+    field.attrs.deserialize_with().is_some_and(|expr| {
+        let segments: Vec<_> = expr
+            .path
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect();
+        segments == ["tsify", "fields", "double_option"]
+    })
+}
@@ -1,50 +1,389 @@
-use proc_macro2::TokenTree;
-use quote::ToTokens;
-use syn::LitStr;
+use std::collections::HashSet;
 
-use crate::typescript::TsType;
+use syn::{punctuated::Punctuated, Token};
 
-/// Extract the documentation comments from a Vec of attributes
-pub fn extract_doc_comments(attrs: &[syn::Attribute]) -> Vec<String> {
-    attrs
+use crate::{
+    attrs::TypeGenerationConfig,
+    typescript::{TsKeywordTypeKind, TsType, TsTypeLit},
+};
+
+/// Extract the documentation comments from a Vec of attributes, followed by JSDoc tags for
+/// `#[deprecated]` and `#[doc(alias)]`. Intra-doc links to the `linkable` declarations, by
+/// their Typescript names, are rewritten as `{@link ...}`.
+pub fn extract_doc_comments(
+    attrs: &[syn::Attribute],
+    config: &TypeGenerationConfig,
+    linkable: &HashSet<String>,
+) -> Vec<String> {
+    let lines = attrs.iter().filter_map(doc_comment).collect::<Vec<_>>();
+    let mut comments = rewrite_doc_comments(lines, config, linkable);
+    comments.extend(attrs.iter().filter_map(deprecated_tag));
+    comments.extend(attrs.iter().flat_map(alias_tags));
+    comments
+}
+
+/// The text of a `#[doc = "..."]` attribute, i.e. a `///` comment.
+fn doc_comment(attr: &syn::Attribute) -> Option<String> {
+    match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("doc") => Some(lit.value()),
+        _ => None,
+    }
+}
+
+/// Rewrite intra-doc links in markdown doc comment `lines` and, if configured, drop the
+/// sections that only make sense to Rust callers.
+fn rewrite_doc_comments(
+    lines: Vec<String>,
+    config: &TypeGenerationConfig,
+    linkable: &HashSet<String>,
+) -> Vec<String> {
+    let mut out = Vec::with_capacity(lines.len());
+    // The info string of the code block we are in, if any.
+    let mut fence: Option<String> = None;
+    // The heading level of the section being stripped, if any.
+    let mut stripped_section: Option<usize> = None;
+
+    for line in lines {
+        let trimmed = line.trim();
+
+        if let Some(info) = &fence {
+            let is_rust = is_rust_fence(info);
+            if trimmed.starts_with("```") {
+                fence = None;
+            }
+            if !(config.strip_rust_docs && (is_rust || stripped_section.is_some())) {
+                out.push(line);
+            }
+            continue;
+        }
+
+        if let Some(info) = trimmed.strip_prefix("```") {
+            fence = Some(info.trim().to_string());
+            if !(config.strip_rust_docs && (is_rust_fence(info) || stripped_section.is_some())) {
+                out.push(line);
+            }
+            continue;
+        }
+
+        if config.strip_rust_docs {
+            if let Some((level, title)) = heading(trimmed) {
+                stripped_section = match stripped_section {
+                    Some(stripped) if level > stripped => Some(stripped),
+                    _ => matches!(title, "Panics" | "Safety").then_some(level),
+                };
+            }
+            if stripped_section.is_some() {
+                continue;
+            }
+        }
+
+        out.push(rewrite_links(&line, config, linkable));
+    }
+
+    if config.strip_rust_docs {
+        while out.last().is_some_and(|line| line.trim().is_empty()) {
+            out.pop();
+        }
+    }
+
+    out
+}
+
+/// Whether a code block with this info string is Rust, as rustdoc would decide for tagged
+/// blocks. Untagged blocks are kept since they are often plain text.
+fn is_rust_fence(info: &str) -> bool {
+    info.split(|c: char| c == ',' || c.is_whitespace())
+        .any(|attr| {
+            matches!(
+                attr,
+                "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail" | "test_harness"
+            ) || attr.starts_with("edition")
+        })
+}
+
+/// The level and title of a markdown ATX heading like `# Panics`.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let title = line[level..].strip_prefix(' ')?;
+    (level > 0).then(|| (level, title.trim()))
+}
+
+/// Rewrite `[`Foo`]`, `[Foo::bar]` and `[text](Foo)` links to `linkable` types as TSDoc
+/// `{@link Foo}`, `{@link Foo.bar}` and `{@link Foo | text}`. Code spans are copied as they are,
+/// and a link to anything else, which has no declaration to point at, becomes its label, set as
+/// code if it is a bare path.
+fn rewrite_links(line: &str, config: &TypeGenerationConfig, linkable: &HashSet<String>) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['[', '`']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with('`') {
+            let len = code_span_len(rest);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let Some(end) = rest.find(']') else {
+            break;
+        };
+        let label = &rest[1..end];
+        let after = &rest[end + 1..];
+
+        // A reference-style link like `[text][Foo]` is left alone.
+        if after.starts_with('[') {
+            let len = after.find(']').map_or(end + 1, |close| end + close + 2);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let inline = after
+            .strip_prefix('(')
+            .and_then(|after| Some(&after[..after.find(')')?]));
+        let link = match inline {
+            Some(target) => Some((target, end + target.len() + 3)),
+            // So is the definition of a reference, `[Foo]: crate::Foo`.
+            None if after.starts_with(':') => None,
+            None if is_shortcut_link(label) => Some((label, end + 1)),
+            None => None,
+        };
+
+        match link.map(|(target, len)| (link_target(target, config, linkable), target, len)) {
+            Some((LinkTarget::Declared(link), target, len)) => {
+                if target == label {
+                    out.push_str(&format!("{{@link {link}}}"));
+                } else {
+                    out.push_str(&format!("{{@link {link} | {}}}", label.replace('`', "")));
+                }
+                rest = &rest[len..];
+            }
+            Some((LinkTarget::Unresolved, target, len)) => {
+                if target == label && !label.starts_with('`') {
+                    out.push_str(&format!("`{label}`"));
+                } else {
+                    out.push_str(label);
+                }
+                rest = &rest[len..];
+            }
+            Some((LinkTarget::Other, ..)) | None => {
+                out.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// The length of the code span at the start of `text`, which starts with a backtick, or of the
+/// run of backticks if it is never closed.
+fn code_span_len(text: &str) -> usize {
+    let ticks = text.len() - text.trim_start_matches('`').len();
+    let mut offset = ticks;
+
+    while let Some(start) = text[offset..].find('`') {
+        let start = offset + start;
+        let run = text[start..].len() - text[start..].trim_start_matches('`').len();
+        if run == ticks {
+            return start + run;
+        }
+        offset = start + run;
+    }
+
+    ticks
+}
+
+/// Whether `[label]` on its own is an intra-doc link, as `[`Foo`]` and `[Foo::bar]` are, rather
+/// than a marker like `[TODO]`, `[X]` or `[x]`.
+fn is_shortcut_link(label: &str) -> bool {
+    if label.starts_with('`') && label.ends_with('`') && label.len() > 1 {
+        return true;
+    }
+
+    label.contains("::")
+        || (label.starts_with(|c: char| c.is_ascii_uppercase())
+            && label.contains(|c: char| c.is_ascii_lowercase()))
+}
+
+enum LinkTarget {
+    /// A type that tsify declares, by its Typescript name.
+    Declared(String),
+    /// A Rust path with no declaration to point at, like `Vec`, `Self::x` or `serde::Serialize`.
+    Unresolved,
+    /// Anything else, such as a URL.
+    Other,
+}
+
+/// What an intra-doc link target like `crate::Foo::bar` refers to. Only the types in `linkable`
+/// resolve: tsify cannot see other declarations, so it links those it knows are declared.
+fn link_target(
+    target: &str,
+    config: &TypeGenerationConfig,
+    linkable: &HashSet<String>,
+) -> LinkTarget {
+    let target = target.trim().trim_matches('`');
+    let target = target.split_once('@').map_or(target, |(_, path)| path);
+    let target = target.trim_end_matches("()").trim_end_matches('!');
+    let Ok(path) = syn::parse_str::<syn::Path>(target) else {
+        return LinkTarget::Other;
+    };
+
+    if path.leading_colon.is_some() {
+        return LinkTarget::Unresolved;
+    }
+
+    let is_type = |ident: &String| ident.starts_with(|c: char| c.is_ascii_uppercase());
+    let mut segments = path
+        .segments
         .iter()
-        .filter_map(|a| {
-            // if the path segments include an ident of "doc" we know this
-            // this is a doc comment
-            if a.path().segments.iter().any(|s| s.ident == "doc") {
-                Some(a.to_token_stream().into_iter().filter_map(|t| match t {
-                    TokenTree::Group(group) => {
-                        // this will return the inner tokens of the group
-                        // which will be the doc comments
-                        Some(
-                            group
-                                .stream()
-                                .into_iter()
-                                .filter_map(|t| match t {
-                                    TokenTree::Literal(lit) => {
-                                        // Parse as LitStr to get the actual string value,
-                                        // regardless of raw string syntax (r"...", r#"..."#, etc.)
-                                        syn::parse2::<LitStr>(lit.into_token_stream())
-                                            .ok()
-                                            .map(|s| s.value())
-                                    }
-                                    _ => None,
-                                })
-                                .collect::<Vec<_>>()
-                                .join(""),
-                        )
-                    }
-                    _ => None,
-                }))
+        .map(|segment| segment.ident.to_string())
+        .skip_while(|ident| !is_type(ident));
+    let Some(ty) = segments.next() else {
+        return LinkTarget::Unresolved;
+    };
+
+    let name = config.format_name(ty);
+    if !linkable.contains(&name) {
+        return LinkTarget::Unresolved;
+    }
+
+    LinkTarget::Declared(
+        std::iter::once(name)
+            .chain(segments)
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// `@deprecated` with the note of a `#[deprecated]` attribute, if any.
+fn deprecated_tag(attr: &syn::Attribute) -> Option<String> {
+    if !attr.path().is_ident("deprecated") {
+        return None;
+    }
+
+    let note = match &attr.meta {
+        syn::Meta::Path(_) => None,
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }),
+            ..
+        }) => Some(lit.value()),
+        syn::Meta::NameValue(_) => None,
+        syn::Meta::List(_) => {
+            let mut note = None;
+            // `since` and anything else is ignored; malformed attributes are rustc's to report.
+            let _ = attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<syn::LitStr>()?;
+                if meta.path.is_ident("note") {
+                    note = Some(value.value());
+                }
+                Ok(())
+            });
+            note
+        }
+    };
+
+    match note {
+        Some(note) => Some(format!(" @deprecated {note}")),
+        None => Some(" @deprecated".to_string()),
+    }
+}
+
+/// `@see` for every name in a `#[doc(alias = "...")]` or `#[doc(alias("...", ...))]`.
+fn alias_tags(attr: &syn::Attribute) -> Vec<String> {
+    let mut aliases = Vec::new();
+
+    if matches!(attr.meta, syn::Meta::List(_)) && attr.path().is_ident("doc") {
+        // Other `doc(...)` items are not interesting here; stop at the first one we can't read.
+        let _ = attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("alias") {
+                return Err(meta.error("not an alias"));
+            }
+
+            if meta.input.peek(Token![=]) {
+                aliases.push(meta.value()?.parse::<syn::LitStr>()?.value());
             } else {
-                None
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let names = Punctuated::<syn::LitStr, Token![,]>::parse_terminated(&content)?;
+                aliases.extend(names.iter().map(syn::LitStr::value));
             }
-        })
-        //Fold up the [[String]] iter we created into Vec<String>
-        .fold(vec![], |mut acc, a| {
-            acc.extend(a);
-            acc
-        })
+            Ok(())
+        });
+    }
+
+    aliases
+        .into_iter()
+        .map(|alias| format!(" @see {alias}"))
+        .collect()
+}
+
+/// `@default` for a field with `#[serde(default)]` whose `Default` value is known, e.g. `0` for
+/// numbers or `[]` for vectors, and nothing where it is not known.
+pub fn default_tag(ts_type: &TsType, ty: &syn::Type) -> Option<String> {
+    let is_char = matches!(ty, syn::Type::Path(p) if p.path.is_ident("char"));
+
+    let value = match ts_type {
+        TsType::Keyword(kind) => match kind {
+            TsKeywordTypeKind::Number => "0",
+            TsKeywordTypeKind::Bigint => "0n",
+            TsKeywordTypeKind::Boolean => "false",
+            TsKeywordTypeKind::String if !is_char => "\"\"",
+            _ => return None,
+        },
+        TsType::Array(_) if is_growable_sequence(ty) => "[]",
+        TsType::Readonly(elem)
+            if matches!(**elem, TsType::Array(_)) && is_growable_sequence(ty) =>
+        {
+            "[]"
+        }
+        _ => return None,
+    };
+
+    Some(format!(" @default {value}"))
+}
+
+/// Whether `ty` is a sequence whose `Default` is empty, like `Vec<T>` or `&[T]`. Tuples and
+/// `[T; N]` are not: their default holds the default of every element.
+fn is_growable_sequence(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(r) => matches!(*r.elem, syn::Type::Slice(_)),
+        syn::Type::Group(g) => is_growable_sequence(&g.elem),
+        syn::Type::Paren(p) => is_growable_sequence(&p.elem),
+        syn::Type::Path(p) => {
+            let Some(last) = p.path.segments.last() else {
+                return false;
+            };
+            match last.ident.to_string().as_str() {
+                "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" | "HashSet" | "BTreeSet" => true,
+                "Box" | "Rc" | "Arc" | "Cow" => match &last.arguments {
+                    syn::PathArguments::AngleBracketed(args) => args
+                        .args
+                        .iter()
+                        .any(|arg| matches!(arg, syn::GenericArgument::Type(syn::Type::Slice(_)))),
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+        _ => false,
+    }
 }
 
 /// Output extracted doc comments as Typescript doc comments.
@@ -65,10 +404,10 @@ pub fn write_doc_comments(
     write!(f, "{}", format_args!("/**\n{} */\n", comment))
 }
 
-/// Remove all comments from a `TsType::TypeLit`
+/// Remove all comments from a `TsType::TypeLit` or `TsType::LabelledTuple`
 pub fn clean_comments(typ: &mut TsType) {
-    if let TsType::TypeLit(ref mut lit) = typ {
-        lit.members.iter_mut().for_each(|elem| {
+    if let TsType::TypeLit(TsTypeLit { members }) | TsType::LabelledTuple(members) = typ {
+        members.iter_mut().for_each(|elem| {
             elem.comments = vec![];
             // Recurse
             clean_comments(&mut elem.type_ann);
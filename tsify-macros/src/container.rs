@@ -1,3 +1,4 @@
+use proc_macro2::{Span, TokenStream};
 use serde_derive_internals::{ast, ast::Container as SerdeContainer, attr};
 
 use crate::{attrs::TsifyContainerAttrs, error_tracker::ErrorTracker};
@@ -124,6 +125,21 @@ impl<'a> Container<'a> {
         self.errors.syn_error(err);
     }
 
+    /// Report something the generated Typescript cannot represent faithfully. This is a
+    /// warning, or an error when the container is marked `#[tsify(strict)]`.
+    pub fn diagnostic(&self, span: Span, msg: &str) {
+        if self.attrs.strict {
+            self.syn_error(syn::Error::new(span, msg));
+        } else {
+            self.errors.warning(span, msg);
+        }
+    }
+
+    /// All accumulated warnings, rendered as tokens.
+    pub fn warnings(&self) -> TokenStream {
+        self.errors.warnings()
+    }
+
     /// Return all accumulated errors.
     pub fn check(self) -> syn::Result<()> {
         self.errors.check()
@@ -4,7 +4,7 @@ use std::{fmt::Display, vec};
 use crate::comments::clean_comments;
 use crate::{
     comments::write_doc_comments,
-    typescript::{TsType, TsTypeElement, TsTypeLit},
+    typescript::{TsFnParam, TsType, TsTypeElement, TsTypeLit},
 };
 
 #[derive(Debug, Clone)]
@@ -17,6 +17,13 @@ pub struct TsTypeAliasDecl {
 }
 
 impl TsTypeAliasDecl {
+    pub fn into_readonly(self) -> Self {
+        Self {
+            type_ann: self.type_ann.into_readonly(),
+            ..self
+        }
+    }
+
     pub fn to_string_with_indent(&self, indent: usize) -> String {
         let out = self.to_string();
         let indent_str = " ".repeat(indent);
@@ -139,6 +146,10 @@ impl TsEnumDecl {
                 t.deref().clone(),
                 type_args,
             ))),
+            TsType::Readonly(t) => TsType::Readonly(Box::new(TsEnumDecl::replace_type_params(
+                t.deref().clone(),
+                type_args,
+            ))),
             TsType::Tuple(tv) => TsType::Tuple(
                 tv.iter()
                     .map(|t| TsEnumDecl::replace_type_params(t.clone(), type_args))
@@ -154,7 +165,10 @@ impl TsEnumDecl {
             TsType::Fn { params, type_ann } => TsType::Fn {
                 params: params
                     .iter()
-                    .map(|t| TsEnumDecl::replace_type_params(t.clone(), type_args))
+                    .map(|p| TsFnParam {
+                        type_ann: TsEnumDecl::replace_type_params(p.type_ann.clone(), type_args),
+                        ..p.clone()
+                    })
                     .collect(),
                 type_ann: Box::new(TsEnumDecl::replace_type_params(
                     type_ann.deref().clone(),
@@ -168,11 +182,22 @@ impl TsEnumDecl {
                     .map(|t| TsTypeElement {
                         key: t.key.clone(),
                         optional: t.optional,
+                        readonly: t.readonly,
                         type_ann: TsEnumDecl::replace_type_params(t.type_ann.clone(), type_args),
                         comments: vec![],
                     })
                     .collect(),
             }),
+            TsType::LabelledTuple(elems) => TsType::LabelledTuple(
+                elems
+                    .iter()
+                    .map(|t| TsTypeElement {
+                        type_ann: TsEnumDecl::replace_type_params(t.type_ann.clone(), type_args),
+                        comments: vec![],
+                        ..t.clone()
+                    })
+                    .collect(),
+            ),
             TsType::Intersection(tv) => TsType::Intersection(
                 tv.iter()
                     .map(|t| TsEnumDecl::replace_type_params(t.clone(), type_args))
@@ -305,6 +330,29 @@ pub enum Decl {
 }
 
 impl Decl {
+    /// Make every member and array of the declaration readonly.
+    pub fn into_readonly(self) -> Self {
+        match self {
+            Decl::TsTypeAlias(decl) => Decl::TsTypeAlias(decl.into_readonly()),
+            Decl::TsInterface(decl) => Decl::TsInterface(TsInterfaceDecl {
+                body: decl
+                    .body
+                    .into_iter()
+                    .map(TsTypeElement::into_readonly)
+                    .collect(),
+                ..decl
+            }),
+            Decl::TsEnum(decl) => Decl::TsEnum(TsEnumDecl {
+                members: decl
+                    .members
+                    .into_iter()
+                    .map(TsTypeAliasDecl::into_readonly)
+                    .collect(),
+                ..decl
+            }),
+        }
+    }
+
     pub fn id(&self) -> &String {
         match self {
             Decl::TsTypeAlias(decl) => &decl.id,
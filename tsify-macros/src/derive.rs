@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{parse_quote, DeriveInput};
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, DeriveInput};
 
-use crate::{container::Container, parser::Parser, wasm_bindgen};
+use crate::{accessors, container::Container, parser::Parser, patch, validate, view, wasm_bindgen};
 
 pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
     let cont = Container::from_derive_input(&input)?;
@@ -30,9 +30,60 @@ pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
         }
     };
 
+    let check_refs = cont
+        .attrs
+        .check_refs
+        .then(|| expand_check_refs(parser.referenced_types()));
+
+    let validate =
+        (cont.attrs.validate && cfg!(feature = "wasm-bindgen")).then(|| validate::expand(&cont));
+
+    let accessors = ((cont.attrs.getters || cont.attrs.setters) && cfg!(feature = "wasm-bindgen"))
+        .then(|| accessors::expand(&cont));
+
+    let patch = cont
+        .attrs
+        .patch
+        .as_ref()
+        .map(|name| patch::expand(&cont, name));
+
+    let views: Vec<_> = cont
+        .attrs
+        .views
+        .iter()
+        .map(|view| view::expand(&cont, view))
+        .collect();
+
+    let warnings = cont.warnings();
+
     cont.check()?;
 
-    Ok(tokens)
+    Ok(quote! {
+        #tokens
+        #check_refs
+        #validate
+        #accessors
+        #patch
+        #(#views)*
+        #warnings
+    })
+}
+
+/// Assert that every referenced type implements `Tsify`, so a missing `#[derive(Tsify)]` fails
+/// the Rust build instead of leaving a dangling name in the `.d.ts`.
+fn expand_check_refs(types: Vec<syn::TypePath>) -> TokenStream {
+    let asserts = types.iter().map(|ty| {
+        quote_spanned! {ty.span()=>
+            assert_tsify::<#ty>();
+        }
+    });
+
+    quote! {
+        const _: fn() = || {
+            fn assert_tsify<T: ?Sized + tsify::Tsify>() {}
+            #(#asserts)*
+        };
+    }
 }
 
 /// Expand an `enum` or `struct` with `#[derive(Tsify)]`.
@@ -50,3 +101,7 @@ pub fn expand_by_attr(args: TokenStream, input: DeriveInput) -> syn::Result<Toke
 
     Ok(tokens)
 }
+
+#[cfg(test)]
+#[path = "derive.test.rs"]
+mod test;
@@ -0,0 +1,895 @@
+use super::expand;
+
+// Warnings are emitted as uses of a `#[deprecated]` constant, so they only show
+// up when the expansion is compiled. These tests assert on the tokens instead.
+
+fn expand_to_string(input: syn::DeriveInput) -> String {
+    expand(input).unwrap().to_string()
+}
+
+fn expand_err(input: syn::DeriveInput) -> String {
+    expand(input)
+        .unwrap_err()
+        .into_iter()
+        .map(|err| err.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn test_serde_with_warns() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo {
+            #[serde(with = "chrono::serde::ts_seconds")]
+            a: DateTime<Utc>,
+            #[serde(serialize_with = "ser")]
+            b: Bar,
+            #[serde(deserialize_with = "de")]
+            c: Baz,
+        }
+    });
+    assert!(tokens.contains("serde(with = \\\"...\\\")"), "{tokens}");
+    assert!(
+        tokens.contains("serde(serialize_with = \\\"...\\\")"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("serde(deserialize_with = \\\"...\\\")"),
+        "{tokens}"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn test_serde_split_with_warns_as_written() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo {
+            #[serde(serialize_with = "ser")]
+            #[serde(deserialize_with = "de")]
+            a: Bar,
+        }
+    });
+    assert!(
+        tokens.contains("serde(serialize_with = \\\"...\\\", deserialize_with = \\\"...\\\")"),
+        "{tokens}"
+    );
+    assert!(!tokens.contains("serde(with = "), "{tokens}");
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn test_serde_with_on_variant_warns() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        enum Foo {
+            #[serde(with = "as_string")]
+            A(u32),
+            #[serde(deserialize_with = "de")]
+            B { b: Bar },
+            C,
+        }
+    });
+    assert!(
+        tokens.contains("serde(with = \\\"...\\\")] changes how this variant"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("serde(deserialize_with = \\\"...\\\")] changes how this variant"),
+        "{tokens}"
+    );
+}
+
+#[test]
+fn test_serde_with_type_override_does_not_warn() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo {
+            #[serde(with = "chrono::serde::ts_seconds")]
+            #[tsify(type = "number")]
+            a: DateTime<Utc>,
+        }
+    });
+    assert!(!tokens.contains("deprecated"), "{tokens}");
+}
+
+#[test]
+fn test_serde_borrow_does_not_warn() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo<'a> {
+            #[serde(borrow)]
+            a: Cow<'a, str>,
+            #[serde(borrow)]
+            b: Cow<'a, [u8]>,
+        }
+    });
+    assert!(!tokens.contains("deprecated"), "{tokens}");
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn test_serde_remote_warns() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[serde(remote = "other::Duration")]
+        struct DurationDef {
+            #[serde(getter = "other::Duration::secs")]
+            secs: i64,
+        }
+    });
+    assert!(tokens.contains("serde(remote = \\\"...\\\")"), "{tokens}");
+    assert!(tokens.contains("serde(getter = \\\"...\\\")"), "{tokens}");
+}
+
+#[test]
+fn test_strict_rejects_serde_with() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(strict)]
+        struct Foo {
+            #[serde(with = "chrono::serde::ts_seconds")]
+            a: DateTime<Utc>,
+        }
+    });
+    assert!(err.contains("#[serde(with = \"...\")]"), "{err}");
+}
+
+#[test]
+fn test_strict_rejects_never_fallbacks() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(strict)]
+        struct Foo {
+            a: *const u8,
+            b: Vec<ty!()>,
+            c: fn(*mut u8) -> !,
+        }
+    });
+    assert_eq!(
+        err.matches("would be emitted as `never`").count(),
+        3,
+        "{err}"
+    );
+    assert!(err.contains("#[tsify(type = \"...\")]"), "{err}");
+}
+
+#[test]
+fn test_strict_allows_type_override() {
+    expand_to_string(syn::parse_quote! {
+        #[tsify(strict)]
+        struct Foo {
+            #[tsify(type = "number")]
+            a: *const u8,
+            b: !,
+        }
+    });
+}
+
+#[test]
+fn test_check_refs() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(check_refs)]
+        struct Foo<'a, T> {
+            a: Address,
+            b: Vec<Option<crate::Phone>>,
+            c: HashMap<String, Address>,
+            d: T,
+            e: Wrapper<T>,
+            f: Wrapper<i32>,
+            g: &'a Borrowed<'a>,
+            #[tsify(type = "string")]
+            h: Overridden,
+            #[serde(skip)]
+            i: Skipped,
+        }
+    });
+    assert!(
+        tokens.contains("assert_tsify :: < Address > ()"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("assert_tsify :: < crate :: Phone > ()"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("assert_tsify :: < Wrapper < i32 > > ()"),
+        "{tokens}"
+    );
+    assert_eq!(tokens.matches("assert_tsify :: <").count(), 3, "{tokens}");
+}
+
+#[test]
+fn test_check_refs_skips_traits() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(check_refs)]
+        struct Foo {
+            a: Box<dyn Trait>,
+            b: Box<dyn Generic<Arg> + Send>,
+            c: Box<dyn Iterator<Item = Item>>,
+            d: Box<dyn Fn(Param) -> Ret>,
+        }
+    });
+    assert!(tokens.contains("assert_tsify :: < Param > ()"), "{tokens}");
+    assert!(tokens.contains("assert_tsify :: < Ret > ()"), "{tokens}");
+    assert_eq!(tokens.matches("assert_tsify :: <").count(), 2, "{tokens}");
+}
+
+#[test]
+fn test_check_refs_matches_lifetimes_as_lifetimes() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(check_refs)]
+        struct Foo<'T, U> {
+            a: &'T Borrowed<'T>,
+            b: Wrapper<U>,
+            c: other::U,
+            d: T,
+        }
+    });
+    assert!(
+        tokens.contains("assert_tsify :: < other :: U > ()"),
+        "{tokens}"
+    );
+    assert!(tokens.contains("assert_tsify :: < T > ()"), "{tokens}");
+    assert_eq!(tokens.matches("assert_tsify :: <").count(), 2, "{tokens}");
+}
+
+#[test]
+fn test_check_refs_is_opt_in() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo {
+            a: Address,
+        }
+    });
+    assert!(!tokens.contains("assert_tsify"), "{tokens}");
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_validate() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        #[serde(rename_all = "camelCase")]
+        struct Foo<T> {
+            unit_price: f64,
+            #[serde(alias = "qty", default)]
+            quantity: T,
+            #[tsify(type = "string")]
+            overridden: Overridden,
+            #[serde(skip)]
+            skipped: Skipped,
+            #[serde(flatten)]
+            rest: Rest,
+        }
+    });
+    assert!(
+        tokens.contains(r#"__v :: flatten :: < Rest > (object , & ["unitPrice" , "quantity" , "qty" , "overridden"] , backend)"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("impl < T > tsify :: Validate for Foo < T > where T : tsify :: Validate"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"__v :: field :: < f64 > (object , & ["unitPrice"] , false , backend)"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(
+            r#"__v :: field :: < T > (object , & ["quantity" , "qty"] , true , backend)"#
+        ),
+        "{tokens}"
+    );
+    assert!(!tokens.contains("Overridden as"), "{tokens}");
+    assert!(!tokens.contains("Skipped"), "{tokens}");
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        struct Foo {
+            a: u32,
+        }
+    });
+    assert!(!tokens.contains("Validate"), "{tokens}");
+}
+
+#[test]
+fn test_invalid_getters() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(getters)]
+        struct Foo(u32, u32);
+    });
+    assert!(
+        err.contains("#[tsify(getters)] can only be used on structs with named fields"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(getters)]
+        struct Foo<T> {
+            a: T,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(getters)] cannot be used on generic types"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(setters)]
+        struct Foo<T> {
+            a: T,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(setters)] cannot be used on generic types"),
+        "{err}"
+    );
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_getters() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(getters)]
+        #[serde(rename_all = "camelCase")]
+        struct Foo {
+            #[serde(default)]
+            item_count: u32,
+            #[tsify(lazy)]
+            items: Vec<Item>,
+            #[serde(default)]
+            #[tsify(lazy)]
+            tags: Vec<String>,
+            labels: HashSet<String>,
+            rgba: [u8; 4],
+            parent: Box<Item>,
+            id: Uuid,
+            children: Vec<Item>,
+            #[serde(flatten)]
+            rest: Rest,
+        }
+    });
+    assert!(tokens.contains("trait FooFields"), "{tokens}");
+    assert!(
+        tokens.contains("impl < C : tsify :: TsConfig > FooFields for tsify :: Ts < Foo , C >"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"fn get_item_count (& self) -> :: core :: result :: Result < u32 , tsify :: Error > { tsify :: fields :: get :: < Foo , u32 > (tsify :: fields :: js (self) , & ["itemCount"] , :: core :: option :: Option :: Some (< u32 as :: core :: default :: Default > :: default)) }"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens
+            .contains(r#"fn get_items (& self) -> tsify :: Ts < Vec < Item > , Self :: Config >"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"fn get_tags (& self) -> :: core :: option :: Option < tsify :: Ts < Vec < String > , Self :: Config > >"#),
+        "{tokens}"
+    );
+    // Fields that are not `lazy` are deserialized, whether or not their type implements `Tsify`.
+    for (getter, ty) in [
+        ("get_labels", "HashSet < String >"),
+        ("get_rgba", "[u8 ; 4]"),
+        ("get_parent", "Box < Item >"),
+        ("get_id", "Uuid"),
+        ("get_children", "Vec < Item >"),
+    ] {
+        assert!(
+            tokens.contains(&format!(
+                "fn {getter} (& self) -> :: core :: result :: Result < {ty} , tsify :: Error >"
+            )),
+            "{tokens}"
+        );
+    }
+    assert!(!tokens.contains("get_rest"), "{tokens}");
+}
+
+#[test]
+fn test_patch() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(patch)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Foo<T> {
+            pub item_count: u32,
+            value: T,
+            #[serde(skip)]
+            cache: Cache,
+            #[serde(deserialize_with = "parse_level")]
+            #[tsify(type = "string")]
+            level: Level,
+        }
+    });
+    assert!(tokens.contains("pub struct FooPatch < T >"), "{tokens}");
+    // `apply` can be called without importing `ApplyPatch`.
+    assert!(
+        tokens.contains("impl < T > Foo < T > { # [doc = \" Sets the fields that `patch` holds, as `ApplyPatch::apply` does.\"] pub fn apply (& mut self , patch : FooPatch < T >) { < Self as tsify :: ApplyPatch > :: apply (self , patch) } }"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"# [serde (rename (serialize = "itemCount" , deserialize = "itemCount") , skip_serializing_if = "Option::is_none")] pub item_count : :: core :: option :: Option < u32 >"#),
+        "{tokens}"
+    );
+    assert!(!tokens.contains("cache"), "{tokens}");
+    assert!(!tokens.contains("level :"), "{tokens}");
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(patch = "FooUpdate")]
+        struct Foo {
+            a: u32,
+        }
+    });
+    assert!(tokens.contains("struct FooUpdate"), "{tokens}");
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(partial = "FooChanges")]
+        struct Foo {
+            a: u32,
+        }
+    });
+    assert!(tokens.contains("struct FooChanges"), "{tokens}");
+    assert!(
+        tokens.contains("impl tsify :: ApplyPatch for Foo { type Patch = FooChanges ;"),
+        "{tokens}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(patch)]
+        enum Foo {
+            A,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(patch)] can only be used on structs with named fields"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(patch, partial = "FooChanges")]
+        struct Foo {
+            a: u32,
+        }
+    });
+    assert!(err.contains("duplicate attribute"), "{err}");
+}
+
+#[test]
+fn test_view() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(view(name = "UserSummary", fields = "id, name"))]
+        #[serde(rename_all = "camelCase", default)]
+        pub struct User {
+            pub id: u32,
+            pub display_name: String,
+            pub name: String,
+        }
+    });
+    assert!(tokens.contains("pub struct UserSummary"), "{tokens}");
+    assert!(
+        tokens.contains(r#"# [serde (rename (serialize = "id" , deserialize = "id") , default = "UserSummary::__tsify_default_id")] pub id : u32 , # [serde (rename (serialize = "name" , deserialize = "name") , default = "UserSummary::__tsify_default_name")] pub name : String ,"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("fn __tsify_default_id () -> u32 { < User as :: core :: default :: Default > :: default () . id }"),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"# [doc = " A view of `User` with only `id`, `name`."]"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("impl :: core :: convert :: From < & User > for UserSummary"),
+        "{tokens}"
+    );
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(view(name = "PublicUser", omit = "password_hash"))]
+        #[serde(rename_all = "camelCase", default = "User::guest")]
+        pub struct User {
+            pub display_name: String,
+            pub password_hash: String,
+        }
+    });
+    assert!(
+        tokens.contains(r#"# [doc = " A view of `User` with only `displayName`."]"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(
+            "fn __tsify_default_display_name () -> String { User :: guest () . display_name }"
+        ),
+        "{tokens}"
+    );
+    assert!(!tokens.contains("pub password_hash"), "{tokens}");
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(view(name = "UserSummary", fields = "id, email"))]
+        struct User {
+            id: u32,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(view)] names `email`, which is not a field of `User`"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(view(name = "UserSummary"))]
+        struct User {
+            id: u32,
+        }
+    });
+    assert!(
+        err.contains(r#"#[tsify(view)] requires `name = "..."` and one of `fields = "..."` or `omit = "..."`"#),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(view(name = "UserSummary", fields = "id", omit = "name"))]
+        struct User {
+            id: u32,
+            name: String,
+        }
+    });
+    assert!(
+        err.contains(r#"#[tsify(view)] requires `name = "..."` and one of `fields = "..."` or `omit = "..."`"#),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(view(name = "Summary", fields = "value"))]
+        struct Wrapper<T> {
+            value: T,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(view)] cannot be used on generic types"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_brand_requires_newtype() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(brand)]
+        struct Foo {
+            a: String,
+            b: String,
+        }
+    });
+    assert!(err.contains("can only be used on newtype structs"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(brand)]
+        struct Unit;
+    });
+    assert!(err.contains("can only be used on newtype structs"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(brand)]
+        enum Foo {
+            A(String),
+        }
+    });
+    assert!(err.contains("can only be used on newtype structs"), "{err}");
+}
+
+#[test]
+fn test_invalid_labels() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(labels = "x")]
+        struct Point(f64, f64);
+    });
+    assert!(
+        err.contains("has 1 labels but the tuple has 2 fields"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        struct Point(#[tsify(label = "x")] f64, f64);
+    });
+    assert!(
+        err.contains("every element of a labelled tuple needs a label"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(labels = "x, not valid")]
+        struct Point(f64, f64);
+    });
+    assert!(
+        err.contains("`not valid` is not a valid tuple label"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(labels = "x")]
+        enum Point {
+            A(f64),
+        }
+    });
+    assert!(err.contains("can only be used on tuple structs"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(labels = "x")]
+        struct Meters(f64);
+    });
+    assert!(err.contains("cannot be used on newtype structs"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(labels = "x, y")]
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+    });
+    assert!(err.contains("can only be used on tuple structs"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        struct Point {
+            #[tsify(label = "horizontal")]
+            x: f64,
+            y: f64,
+        }
+    });
+    assert!(err.contains("can only be used on tuple fields"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        struct Meters(#[tsify(label = "value")] f64);
+    });
+    assert!(
+        err.contains("cannot be used on the field of a newtype"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_invalid_params() {
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "a")]
+            f: String,
+        }
+    });
+    assert!(
+        err.contains("can only be used on fields with a function type"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "a")]
+            f: fn(u8, u8),
+        }
+    });
+    assert!(
+        err.contains("has 1 names but the function has 2 parameters"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "a?, b")]
+            f: fn(Option<u8>, u8),
+        }
+    });
+    assert!(err.contains("cannot follow an optional parameter"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "...a, b")]
+            f: fn(Vec<u8>, u8),
+        }
+    });
+    assert!(err.contains("must be the last parameter"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "...a")]
+            f: fn(u8),
+        }
+    });
+    assert!(err.contains("must have an array or tuple type"), "{err}");
+
+    let err = expand_err(syn::parse_quote! {
+        struct Foo {
+            #[tsify(params = "a-b")]
+            f: fn(u8),
+        }
+    });
+    assert!(err.contains("`a-b` is not a valid parameter name"), "{err}");
+}
+
+#[test]
+#[cfg(feature = "js")]
+fn test_json_compatible_conflicts() {
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(json_compatible, missing_as_null)]
+        struct Foo {
+            a: Option<u32>,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(json_compatible)] already implies `missing_as_null`"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(bytes_as_arrays, json_compatible)]
+        struct Foo {
+            a: Option<u32>,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(json_compatible)] already implies `bytes_as_arrays`"),
+        "{err}"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "js"))]
+fn test_backend() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(backend = "json")]
+        struct Foo {
+            a: Option<u32>,
+            b: HashMap<String, u128>,
+        }
+    });
+    assert!(
+        tokens.contains(r"a: number | null;\n    b: Record<string, number>;"),
+        "{tokens}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(missing_as_null, backend = "json")]
+        struct Foo {
+            a: Option<u32>,
+        }
+    });
+    assert!(
+        err.contains("#[tsify(missing_as_null)] requires `backend = \"js\"`"),
+        "{err}"
+    );
+
+    let err = expand_err(syn::parse_quote! {
+        #[tsify(backend = "yaml")]
+        struct Foo {
+            a: u32,
+        }
+    });
+    assert!(
+        err.contains("expected `backend = \"json\"` or `backend = \"js\"`"),
+        "{err}"
+    );
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_validate_internally_tagged() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        #[serde(tag = "type", deny_unknown_fields)]
+        enum Event {
+            Click { x: i32 },
+            Key(KeyEvent),
+            Close,
+        }
+    });
+    assert!(
+        tokens.contains(r#"let variant = __v :: tag (object , "type") ?"#),
+        "{tokens}"
+    );
+    // The tag is one of the known properties of a struct variant.
+    assert!(
+        tokens.contains(r#"__v :: deny_unknown_fields (object , & ["x" , "type"] , backend) ?"#),
+        "{tokens}"
+    );
+    // A newtype variant sees the object without the tag, as serde hands it over.
+    assert!(
+        tokens.contains(r#"__v :: flatten :: < KeyEvent > (js , & ["type"] , backend)"#),
+        "{tokens}"
+    );
+    assert!(
+        !tokens.contains("KeyEvent as tsify :: Validate"),
+        "{tokens}"
+    );
+    // serde does not read the rest of an internally tagged object from an array.
+    assert!(!tokens.contains("struct_array"), "{tokens}");
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_validate_adjacently_tagged() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        #[serde(tag = "t", content = "c", deny_unknown_fields)]
+        enum Message {
+            Text { body: String },
+            Code(u32),
+        }
+    });
+    assert!(
+        tokens.contains(r#"__v :: deny_unknown_fields (object , & ["t" , "c"] , backend) ?"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"__v :: deny_unknown_fields (object , & ["body"] , backend) ?"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"__v :: property (object , "c")"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("< u32 as tsify :: Validate > :: validate (js , backend)"),
+        "{tokens}"
+    );
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_validate_flatten_deny_unknown_fields() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        #[serde(deny_unknown_fields)]
+        struct Outer {
+            #[serde(rename = "ID", alias = "id")]
+            id: u32,
+            #[serde(flatten)]
+            inner: Inner,
+        }
+    });
+    assert!(
+        tokens.contains(r#"__v :: deny_unknown_fields (object , & ["ID" , "id"] , backend) ?"#),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains(r#"__v :: flatten :: < Inner > (object , & ["ID" , "id"] , backend) ?"#),
+        "{tokens}"
+    );
+    // serde reads a struct with a flattened field only from a map.
+    assert!(!tokens.contains("struct_array"), "{tokens}");
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        struct Open {
+            #[serde(flatten)]
+            inner: Inner,
+        }
+    });
+    assert!(!tokens.contains("deny_unknown_fields"), "{tokens}");
+}
+
+#[test]
+#[cfg(feature = "wasm-bindgen")]
+fn test_validate_struct_from_array() {
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        struct Point {
+            x: f64,
+            y: f64,
+            #[serde(default)]
+            z: f64,
+        }
+    });
+    // serde_json reads a struct from an array of its fields; the defaulted ones may be missing.
+    assert!(
+        tokens.contains(
+            r#"if ! backend . is_js () { if let :: core :: option :: Option :: Some (array) = __v :: struct_array (js , 2u32 , 3u32 , "struct Point with 3 elements") ?"#
+        ),
+        "{tokens}"
+    );
+    assert!(
+        tokens.contains("__v :: struct_element :: < f64 > (& array , 2u32 , backend) ?"),
+        "{tokens}"
+    );
+
+    let tokens = expand_to_string(syn::parse_quote! {
+        #[tsify(validate)]
+        #[serde(untagged)]
+        enum Shape {
+            Circle { radius: f64 },
+        }
+    });
+    // An untagged variant is buffered first, which reads it from an array under either backend.
+    assert!(
+        tokens.contains(
+            r#"if true { if let :: core :: option :: Option :: Some (array) = __v :: struct_array (js , 1u32 , 1u32 , "variant Shape::Circle with 1 element") ?"#
+        ),
+        "{tokens}"
+    );
+}
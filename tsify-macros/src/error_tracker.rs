@@ -1,10 +1,14 @@
 use std::cell::RefCell;
 
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+
 /// Tracks errors during macro expansion. This struct implements a panic on `Drop`
 /// if there are accumulated errors that weren't checked.
 ///
 /// By using an error tracker, you can accumulate errors inside of closures and still propagate
-/// them when needed.
+/// them when needed. Warnings are accumulated alongside errors and are emitted as tokens
+/// through [`ErrorTracker::warnings`].
 ///
 /// # Example
 /// ```ignore
@@ -15,12 +19,14 @@ use std::cell::RefCell;
 /// ```
 pub struct ErrorTracker {
     errors: RefCell<Option<Vec<syn::Error>>>,
+    warnings: RefCell<Vec<(Span, String)>>,
 }
 
 impl ErrorTracker {
     pub fn new() -> Self {
         Self {
             errors: RefCell::new(Some(Vec::new())),
+            warnings: RefCell::new(Vec::new()),
         }
     }
 
@@ -29,6 +35,21 @@ impl ErrorTracker {
         self.errors.borrow_mut().as_mut().unwrap().push(err)
     }
 
+    /// Add a warning that will be reported at `span`.
+    pub fn warning(&self, span: Span, note: impl Into<String>) {
+        self.warnings.borrow_mut().push((span, note.into()))
+    }
+
+    /// Render all accumulated warnings as deprecation warnings. Proc macros have no stable way to
+    /// emit warnings, so this is the closest we can get.
+    pub fn warnings(&self) -> TokenStream {
+        self.warnings
+            .borrow()
+            .iter()
+            .map(|(span, note)| mark_deprecated(*span, note))
+            .collect()
+    }
+
     /// Return all accumulated errors. This also clears the list of errors.
     pub fn check(self) -> syn::Result<()> {
         let mut errors = self.errors.take().unwrap().into_iter();
@@ -53,3 +74,15 @@ impl Drop for ErrorTracker {
         }
     }
 }
+
+/// Emit a deprecation warning with the given `note` at `span`.
+pub fn mark_deprecated(span: Span, note: &str) -> TokenStream {
+    quote_spanned!(span =>
+        #[allow(non_upper_case_globals)]
+        const _: () = {
+            #[deprecated(note = #note)]
+            const _x: () = ();
+            _x
+        };
+    )
+}
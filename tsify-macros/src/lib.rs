@@ -1,3 +1,4 @@
+mod accessors;
 mod attrs;
 mod comments;
 mod container;
@@ -5,8 +6,11 @@ mod decl;
 mod derive;
 mod error_tracker;
 mod parser;
+mod patch;
 mod type_alias;
 mod typescript;
+mod validate;
+mod view;
 mod wasm_bindgen;
 
 use syn::{parse_macro_input, DeriveInput};
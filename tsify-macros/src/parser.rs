@@ -1,21 +1,27 @@
-use std::collections::HashSet;
+use std::{cell::RefCell, collections::HashSet};
 
+use quote::ToTokens;
 use serde_derive_internals::{
     ast::{Data, Field, Style, Variant},
-    attr::TagType,
+    attr::{self, TagType},
 };
+use syn::spanned::Spanned;
 
 use crate::{
-    attrs::TsifyFieldAttrs,
-    comments::extract_doc_comments,
+    attrs::{custom_deserialize_with, is_double_option, TsifyFieldAttrs, TypeGenerationConfig},
+    comments::{default_tag, extract_doc_comments},
     container::Container,
     decl::{Decl, TsEnumDecl, TsInterfaceDecl, TsTypeAliasDecl},
-    typescript::{TsType, TsTypeElement, TsTypeLit},
+    typescript::{
+        is_js_ident, referenced_types, unsupported_types, NullType, TsType, TsTypeElement,
+        TsTypeLit,
+    },
 };
 
 enum ParsedFields {
     Named(Vec<TsTypeElement>, Vec<TsType>),
     Unnamed(Vec<TsType>),
+    Labelled(Vec<TsTypeElement>),
     Transparent(TsType),
 }
 
@@ -32,11 +38,15 @@ impl From<ParsedFields> for TsType {
                 }
             }
             ParsedFields::Unnamed(elems) => TsType::Tuple(elems),
+            ParsedFields::Labelled(elems) => TsType::LabelledTuple(elems),
             ParsedFields::Transparent(ty) => ty,
         }
     }
 }
 
+const NAMED_LABEL_ERROR: &str =
+    "#[tsify(label)] can only be used on tuple fields; a named field is already labelled by its name";
+
 enum FieldsStyle {
     Named,
     Unnamed,
@@ -45,15 +55,39 @@ enum FieldsStyle {
 #[derive(Clone)]
 pub struct Parser<'a> {
     pub container: &'a Container<'a>,
+    /// Types referenced by the parsed fields, collected for `#[tsify(check_refs)]`.
+    referenced_types: RefCell<Vec<syn::TypePath>>,
+    /// The Typescript names doc comments may link to.
+    linkable: HashSet<String>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(container: &'a Container<'a>) -> Self {
-        Self { container }
+        Self {
+            container,
+            referenced_types: RefCell::new(Vec::new()),
+            linkable: linkable_names(container),
+        }
+    }
+
+    /// The types referenced by the declaration, excluding the container's own type
+    /// parameters and anything depending on them. Only populated by [`Parser::parse`] under
+    /// `#[tsify(check_refs)]`.
+    pub fn referenced_types(&self) -> Vec<syn::TypePath> {
+        self.referenced_types.borrow().clone()
     }
 
     pub fn parse(&self) -> Decl {
-        if let Some(decl) = &self.container.attrs.type_override {
+        if self.container.attrs.type_override.is_none() {
+            if let Some(remote) = self.container.serde_attrs().remote() {
+                self.container.diagnostic(
+                    remote.span(),
+                    "#[serde(remote = \"...\")] types cannot be passed through `Tsify` directly; add #[tsify(type = \"...\")] to specify the Typescript type",
+                );
+            }
+        }
+
+        let decl = if let Some(decl) = &self.container.attrs.type_override {
             self.create_type_alias_decl(TsType::Override {
                 type_override: decl.to_string(),
                 type_params: self
@@ -68,9 +102,19 @@ impl<'a> Parser<'a> {
                 Data::Struct(style, ref fields) => self.parse_struct(*style, fields),
                 Data::Enum(ref variants) => self.parse_enum(variants),
             }
+        };
+
+        if self.container.attrs.readonly {
+            decl.into_readonly()
+        } else {
+            decl
         }
     }
 
+    fn doc_comments(&self, attrs: &[syn::Attribute]) -> Vec<String> {
+        extract_doc_comments(attrs, &self.container.attrs.ty_config, &self.linkable)
+    }
+
     fn create_relevant_type_params(&self, type_ref_names: HashSet<&String>) -> Vec<String> {
         self.container
             .generics()
@@ -92,7 +136,7 @@ impl<'a> Parser<'a> {
                 .cloned()
                 .unwrap_or_else(|| self.create_relevant_type_params(type_ann.type_ref_names())),
             type_ann,
-            comments: extract_doc_comments(&self.container.serde_container.original.attrs),
+            comments: self.doc_comments(&self.container.serde_container.original.attrs),
         })
     }
 
@@ -120,7 +164,7 @@ impl<'a> Parser<'a> {
                 type_params,
                 extends,
                 body: members,
-                comments: extract_doc_comments(&self.container.serde_container.original.attrs),
+                comments: self.doc_comments(&self.container.serde_container.original.attrs),
             })
         } else {
             let extra = TsType::Intersection(
@@ -142,6 +186,21 @@ impl<'a> Parser<'a> {
         let parsed_fields = self.parse_fields(style, fields);
         let tag_type = self.container.serde_attrs().tag();
 
+        if self.container.attrs.brand.is_some() {
+            let ty = match parsed_fields {
+                ParsedFields::Transparent(ty) if !matches!(style, Style::Unit) => self.brand(ty),
+                parsed_fields => {
+                    self.container.syn_error(syn::Error::new_spanned(
+                        self.container.ident(),
+                        "#[tsify(brand)] can only be used on newtype structs",
+                    ));
+                    parsed_fields.into()
+                }
+            };
+
+            return self.create_type_alias_decl(ty);
+        }
+
         match (tag_type, parsed_fields) {
             (TagType::Internal { tag }, ParsedFields::Named(members, extends)) => {
                 let name = self.container.name();
@@ -150,6 +209,7 @@ impl<'a> Parser<'a> {
                     key: tag.clone(),
                     type_ann: TsType::Lit(name),
                     optional: false,
+                    readonly: false,
                     comments: vec![],
                 };
 
@@ -164,10 +224,36 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Intersect `ty` with a readonly brand property named after the type, so that Typescript
+    /// treats distinct newtypes over the same type as incompatible.
+    fn brand(&self, ty: TsType) -> TsType {
+        let key = self.container.attrs.brand.clone().unwrap_or_default();
+        let brand = TsTypeElement {
+            key,
+            type_ann: TsType::Lit(self.container.ident_str()),
+            optional: false,
+            readonly: true,
+            comments: vec![],
+        };
+
+        ty.and(brand.into())
+    }
+
     fn parse_fields(&self, style: Style, fields: &[Field]) -> ParsedFields {
+        if self.container.attrs.labels.is_some() && !matches!(style, Style::Tuple) {
+            let msg = match style {
+                Style::Newtype => "#[tsify(labels)] cannot be used on newtype structs, which are serialized as their field",
+                _ => "#[tsify(labels)] can only be used on tuple structs",
+            };
+            self.container
+                .syn_error(syn::Error::new_spanned(self.container.ident(), msg));
+        }
+
         let style = match style {
             Style::Struct => FieldsStyle::Named,
-            Style::Newtype => return ParsedFields::Transparent(self.parse_field(&fields[0]).0),
+            Style::Newtype => {
+                return ParsedFields::Transparent(self.parse_transparent_field(&fields[0]))
+            }
             Style::Tuple => FieldsStyle::Unnamed,
             Style::Unit => {
                 return ParsedFields::Transparent(TsType::nullish(&self.container.attrs.ty_config))
@@ -184,7 +270,13 @@ impl<'a> Parser<'a> {
             .collect::<Vec<_>>();
 
         if fields.len() == 1 && self.container.transparent() {
-            return ParsedFields::Transparent(self.parse_field(fields[0]).0);
+            if self.container.attrs.labels.is_some() {
+                self.container.syn_error(syn::Error::new_spanned(
+                    self.container.ident(),
+                    "#[tsify(labels)] cannot be used on #[serde(transparent)] structs, which are serialized as their field",
+                ));
+            }
+            return ParsedFields::Transparent(self.parse_transparent_field(fields[0]));
         }
 
         match style {
@@ -193,15 +285,101 @@ impl<'a> Parser<'a> {
 
                 ParsedFields::Named(members, flatten_fields)
             }
-            FieldsStyle::Unnamed => {
-                let elems = fields
-                    .into_iter()
-                    .map(|field| self.parse_field(field).0)
-                    .collect();
+            FieldsStyle::Unnamed => self.parse_unnamed_fields(fields),
+        }
+    }
+
+    /// Parse the only field of a type that is serialized as that field, where a label has
+    /// nothing to name.
+    fn parse_transparent_field(&self, field: &Field) -> TsType {
+        let (type_ann, field_attrs) = self.parse_field(field);
+        self.reject_label(
+            field,
+            field_attrs.as_ref(),
+            "#[tsify(label)] cannot be used on the field of a newtype, which is serialized as the field itself",
+        );
+        type_ann
+    }
+
+    fn reject_label(&self, field: &Field, field_attrs: Option<&TsifyFieldAttrs>, msg: &str) {
+        if field_attrs.is_some_and(|attrs| attrs.label.is_some()) {
+            self.container
+                .syn_error(syn::Error::new_spanned(field.original, msg));
+        }
+    }
 
-                ParsedFields::Unnamed(elems)
+    fn parse_unnamed_fields(&self, fields: Vec<&Field>) -> ParsedFields {
+        let labels = self.container.attrs.labels.as_ref();
+
+        if let Some(labels) = labels {
+            if labels.len() != fields.len() {
+                let msg = format!(
+                    "#[tsify(labels)] has {} labels but the tuple has {} fields",
+                    labels.len(),
+                    fields.len()
+                );
+                self.container
+                    .syn_error(syn::Error::new_spanned(self.container.ident(), msg));
+                return ParsedFields::Unnamed(vec![TsType::NEVER; fields.len()]);
             }
         }
+
+        let elems = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let (type_ann, field_attrs) = self.parse_field(field);
+                let label = field_attrs
+                    .and_then(|attrs| attrs.label)
+                    .or_else(|| labels.map(|labels| labels[i].clone()));
+                (type_ann, label)
+            })
+            .collect::<Vec<_>>();
+
+        if elems.iter().all(|(_, label)| label.is_none()) {
+            return ParsedFields::Unnamed(elems.into_iter().map(|(ty, _)| ty).collect());
+        }
+
+        // Serde only accepts `#[serde(default)]` on trailing fields, so these stay trailing.
+        let container_default = !self.container.serde_attrs().default().is_none();
+
+        let elems = fields
+            .iter()
+            .zip(elems)
+            .map(|(field, (type_ann, label))| {
+                let key = match label {
+                    Some(label) if is_js_ident(&label) => label,
+                    Some(label) => {
+                        let msg = format!("`{label}` is not a valid tuple label");
+                        self.container
+                            .syn_error(syn::Error::new_spanned(field.original, msg));
+                        label
+                    }
+                    None => {
+                        self.container.syn_error(syn::Error::new_spanned(
+                            field.original,
+                            "every element of a labelled tuple needs a label; add #[tsify(label = \"...\")]",
+                        ));
+                        String::new()
+                    }
+                };
+
+                let mut comments = self.doc_comments(&field.original.attrs);
+                if let attr::Default::Default = field.attrs.default() {
+                    comments.extend(default_tag(&type_ann, field.ty));
+                }
+
+                TsTypeElement {
+                    key,
+                    type_ann,
+                    optional: container_default || !field.attrs.default().is_none(),
+                    readonly: false,
+                    comments,
+                }
+            })
+            .collect();
+
+        ParsedFields::Labelled(elems)
     }
 
     fn parse_field(&self, field: &Field) -> (TsType, Option<TsifyFieldAttrs>) {
@@ -215,6 +393,12 @@ impl<'a> Parser<'a> {
 
         let type_ann = TsType::from_syn_type(&self.container.attrs.ty_config, field.ty);
 
+        if ts_attrs.type_override.is_none() {
+            self.check_custom_serde(field);
+            self.check_unsupported_types(field.ty);
+            self.collect_referenced_types(field.ty);
+        }
+
         if let Some(t) = &ts_attrs.type_override {
             let type_params = if let Some(params) = &ts_attrs.type_params {
                 params.clone()
@@ -230,7 +414,159 @@ impl<'a> Parser<'a> {
                 Some(ts_attrs),
             )
         } else {
-            (type_ann, Some(ts_attrs))
+            let type_ann = match &ts_attrs.params {
+                Some(params) => self.name_fn_params(field, type_ann, params),
+                None => type_ann,
+            };
+
+            if ts_attrs.readonly {
+                (type_ann.into_readonly(), Some(ts_attrs))
+            } else {
+                (type_ann, Some(ts_attrs))
+            }
+        }
+    }
+
+    /// Apply `#[tsify(params = "...")]` to the function type of a field. A name ending in `?`
+    /// makes the parameter optional, and a name starting with `...` makes it a rest parameter.
+    fn name_fn_params(&self, field: &Field, mut type_ann: TsType, names: &[String]) -> TsType {
+        let error = |msg: String| {
+            self.container
+                .syn_error(syn::Error::new_spanned(field.original, msg));
+        };
+
+        let Some(params) = type_ann.fn_params_mut() else {
+            error("#[tsify(params)] can only be used on fields with a function type".to_string());
+            return type_ann;
+        };
+
+        if params.len() != names.len() {
+            error(format!(
+                "#[tsify(params)] has {} names but the function has {} parameters",
+                names.len(),
+                params.len()
+            ));
+            return type_ann;
+        }
+
+        let len = params.len();
+        let mut after_optional = false;
+
+        for (i, (param, name)) in params.iter_mut().zip(names).enumerate() {
+            let (rest, name) = match name.strip_prefix("...") {
+                Some(name) => (true, name),
+                None => (false, name.as_str()),
+            };
+            let (optional, name) = match name.strip_suffix('?') {
+                Some(name) => (true, name),
+                None => (false, name),
+            };
+
+            if !is_js_ident(name) {
+                error(format!("`{name}` is not a valid parameter name"));
+            } else if rest && (optional || i + 1 != len) {
+                error(format!(
+                    "`...{name}` must be the last parameter and cannot be optional"
+                ));
+            } else if rest && !is_array_like(&param.type_ann) {
+                error(format!(
+                    "the rest parameter `...{name}` must have an array or tuple type"
+                ));
+            } else if after_optional && !optional && !rest {
+                error(format!(
+                    "the required parameter `{name}` cannot follow an optional parameter"
+                ));
+            }
+
+            after_optional |= optional;
+
+            let type_ann = std::mem::replace(&mut param.type_ann, TsType::NEVER);
+            param.type_ann = match type_ann {
+                TsType::Option(ty, _) if optional => *ty,
+                ty => ty,
+            };
+            param.name = Some(name.to_string());
+            param.optional = optional;
+            param.rest = rest;
+        }
+
+        type_ann
+    }
+
+    /// Serde attributes that replace the serialization of a field make its Rust type
+    /// meaningless for the generated Typescript.
+    fn check_custom_serde(&self, field: &Field) {
+        let attr = custom_serde_attr(
+            &field.original.attrs,
+            field.attrs.serialize_with().is_some(),
+            custom_deserialize_with(field).is_some(),
+            field.attrs.getter().is_some(),
+        );
+        if let Some(attr) = attr {
+            let msg = format!(
+                "{attr} changes how this field is serialized, so its Rust type does not describe it; add #[tsify(type = \"...\")] to specify the Typescript type"
+            );
+            self.container.diagnostic(field.original.span(), &msg);
+        }
+    }
+
+    /// The same for a variant, whose fields then no longer describe it. There is no
+    /// `#[tsify(type)]` on variants, so the override goes on the enum.
+    fn check_custom_serde_variant(&self, variant: &Variant) {
+        let attr = custom_serde_attr(
+            &variant.original.attrs,
+            variant.attrs.serialize_with().is_some(),
+            variant.attrs.deserialize_with().is_some(),
+            false,
+        );
+        if let Some(attr) = attr {
+            let msg = format!(
+                "{attr} changes how this variant is serialized, so its Rust fields do not describe it; add #[tsify(type = \"...\")] to the enum to specify the Typescript type"
+            );
+            self.container.diagnostic(variant.original.span(), &msg);
+        }
+    }
+
+    /// Under `#[tsify(strict)]`, reject types that would silently become `never`.
+    fn check_unsupported_types(&self, ty: &syn::Type) {
+        if !self.container.attrs.strict {
+            return;
+        }
+
+        for ty in unsupported_types(ty) {
+            self.container.syn_error(unsupported_type_error(ty));
+        }
+    }
+
+    fn collect_referenced_types(&self, ty: &syn::Type) {
+        if !self.container.attrs.check_refs {
+            return;
+        }
+
+        let generics = self.container.generics();
+        let mut params = HashSet::new();
+        let mut lifetimes = HashSet::new();
+        for param in &generics.params {
+            match param {
+                syn::GenericParam::Type(p) => params.insert(p.ident.clone()),
+                syn::GenericParam::Const(p) => params.insert(p.ident.clone()),
+                syn::GenericParam::Lifetime(p) => lifetimes.insert(p.lifetime.ident.clone()),
+            };
+        }
+        params.insert(syn::Ident::new("Self", proc_macro2::Span::call_site()));
+
+        let mut collected = self.referenced_types.borrow_mut();
+        for type_path in referenced_types(&self.container.attrs.ty_config, ty) {
+            let tokens = type_path.to_token_stream();
+            let is_generic =
+                type_path.qself.is_some() || mentions_any(tokens.clone(), &params, &lifetimes);
+            let is_duplicate = collected
+                .iter()
+                .any(|t| t.to_token_stream().to_string() == tokens.to_string());
+
+            if !is_generic && !is_duplicate {
+                collected.push(type_path.clone());
+            }
         }
     }
 
@@ -243,8 +579,10 @@ impl<'a> Parser<'a> {
             .map(|field| {
                 let key = field.attrs.name().serialize_name().to_owned();
                 let (type_ann, field_attrs) = self.parse_field(field);
+                self.reject_label(field, field_attrs.as_ref(), NAMED_LABEL_ERROR);
 
-                let optional = field_attrs.is_some_and(|attrs| attrs.optional);
+                let optional = field_attrs.as_ref().is_some_and(|attrs| attrs.optional);
+                let readonly = field_attrs.as_ref().is_some_and(|attrs| attrs.readonly);
                 let default_is_none = self.container.serde_attrs().default().is_none()
                     && field.attrs.default().is_none();
 
@@ -257,12 +595,24 @@ impl<'a> Parser<'a> {
                     type_ann
                 };
 
-                let comments = extract_doc_comments(&field.original.attrs);
+                // `null` clears the field in a patch, whichever backend reads it.
+                let type_ann = match type_ann {
+                    TsType::Option(t, _) if is_double_option(field) => {
+                        TsType::Option(t, NullType::Null)
+                    }
+                    _ => type_ann,
+                };
+
+                let mut comments = self.doc_comments(&field.original.attrs);
+                if let attr::Default::Default = field.attrs.default() {
+                    comments.extend(default_tag(&type_ann, field.ty));
+                }
 
                 TsTypeElement {
                     key,
                     type_ann,
                     optional: optional || !default_is_none,
+                    readonly,
                     comments,
                 }
             })
@@ -270,7 +620,11 @@ impl<'a> Parser<'a> {
 
         let flatten_fields = flatten_fields
             .into_iter()
-            .map(|field| self.parse_field(field).0)
+            .map(|field| {
+                let (type_ann, field_attrs) = self.parse_field(field);
+                self.reject_label(field, field_attrs.as_ref(), NAMED_LABEL_ERROR);
+                type_ann
+            })
             .collect();
 
         (members, flatten_fields)
@@ -288,7 +642,7 @@ impl<'a> Parser<'a> {
                         .name()
                         .serialize_name()
                         .clone_into(&mut type_alias.id);
-                    type_alias.comments = extract_doc_comments(&variant.original.attrs);
+                    type_alias.comments = self.doc_comments(&variant.original.attrs);
 
                     type_alias
                 } else {
@@ -309,11 +663,13 @@ impl<'a> Parser<'a> {
             type_params: relevant_type_params,
             members,
             namespace: self.container.attrs.namespace,
-            comments: extract_doc_comments(&self.container.serde_container.original.attrs),
+            comments: self.doc_comments(&self.container.serde_container.original.attrs),
         })
     }
 
     fn parse_variant(&self, variant: &Variant) -> TsType {
+        self.check_custom_serde_variant(variant);
+
         let tag_type = self.container.serde_attrs().tag();
         let name = variant.attrs.name().serialize_name().to_owned();
         // Checks for Newtype with a skip attribute and treats it as a Unit
@@ -331,6 +687,120 @@ impl<'a> Parser<'a> {
     }
 }
 
+/// The declarations a doc comment of `container` can link to: the container itself and the types
+/// its fields refer to, which must be declared for the Typescript to compile.
+fn linkable_names(container: &Container) -> HashSet<String> {
+    let config = &container.attrs.ty_config;
+    let params = container
+        .generics()
+        .type_params()
+        .map(|p| config.format_name(p.ident.to_string()))
+        .collect::<HashSet<_>>();
+    let fields: Vec<&Field> = match container.serde_data() {
+        Data::Struct(_, fields) => fields.iter().collect(),
+        Data::Enum(variants) => variants.iter().flat_map(|v| &v.fields).collect(),
+    };
+
+    let mut names = HashSet::from([container.ident_str()]);
+    names.extend(
+        fields
+            .into_iter()
+            .flat_map(|field| linkable_types(config, field.ty))
+            .filter(|name| !params.contains(name)),
+    );
+    names
+}
+
+/// The Typescript names of the declared types that `ty` refers to.
+pub fn linkable_types(config: &TypeGenerationConfig, ty: &syn::Type) -> Vec<String> {
+    referenced_types(config, ty)
+        .into_iter()
+        .filter_map(|type_path| type_path.path.segments.last())
+        .map(|segment| config.format_name(segment.ident.to_string()))
+        .collect()
+}
+
+pub fn unsupported_type_error(ty: &syn::Type) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        "this type has no Typescript equivalent and would be emitted as `never`; add #[tsify(type = \"...\")] to specify the Typescript type",
+    )
+}
+
+/// The serde attribute that replaces the serialization, as written in `attrs`, if any. Only the
+/// names whose effect is set are listed, so `deserialize_with` that serde fills in itself is left
+/// out.
+fn custom_serde_attr(
+    attrs: &[syn::Attribute],
+    serialize_with: bool,
+    deserialize_with: bool,
+    getter: bool,
+) -> Option<String> {
+    let mut names = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        // Malformed attributes are serde's to report.
+        let Ok(metas) = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        ) else {
+            continue;
+        };
+
+        for meta in metas {
+            let name = match meta.path().get_ident() {
+                Some(ident) if ident == "with" && serialize_with => "with",
+                Some(ident) if ident == "serialize_with" && serialize_with => "serialize_with",
+                Some(ident) if ident == "deserialize_with" && deserialize_with => {
+                    "deserialize_with"
+                }
+                Some(ident) if ident == "getter" && getter => "getter",
+                _ => continue,
+            };
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    if names.is_empty() {
+        return None;
+    }
+
+    let list = names
+        .iter()
+        .map(|name| format!("{name} = \"...\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("#[serde({list})]"))
+}
+
+/// Whether `tokens` use one of the type or const parameters `params`, as the start of a path,
+/// or one of the `lifetimes`. The assertions live outside the container's generics, so any of
+/// them would be unbound there.
+fn mentions_any(
+    tokens: proc_macro2::TokenStream,
+    params: &HashSet<syn::Ident>,
+    lifetimes: &HashSet<syn::Ident>,
+) -> bool {
+    use proc_macro2::TokenTree;
+
+    let mut prev: Option<TokenTree> = None;
+    tokens.into_iter().any(|token| {
+        let found = match &token {
+            TokenTree::Ident(ident) => match &prev {
+                Some(TokenTree::Punct(p)) if p.as_char() == '\'' => lifetimes.contains(ident),
+                // The rest of a path, like `T` in `other::T`.
+                Some(TokenTree::Punct(p)) if p.as_char() == ':' => false,
+                _ => params.contains(ident),
+            },
+            TokenTree::Group(group) => mentions_any(group.stream(), params, lifetimes),
+            _ => false,
+        };
+        prev = Some(token);
+        found
+    })
+}
+
 fn is_phantom(ty: &syn::Type) -> bool {
     if let syn::Type::Path(syn::TypePath { path, .. }) = ty {
         path.segments
@@ -340,3 +810,10 @@ fn is_phantom(ty: &syn::Type) -> bool {
         false
     }
 }
+
+fn is_array_like(ty: &TsType) -> bool {
+    matches!(
+        ty,
+        TsType::Array(_) | TsType::Tuple(_) | TsType::LabelledTuple(_) | TsType::Readonly(_)
+    )
+}
@@ -0,0 +1,233 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use serde_derive_internals::ast::{Data, Field};
+use syn::parse_quote;
+
+use crate::{
+    accessors::{expand_write, is_written, last_ident_is},
+    attrs::{custom_deserialize_with, Backend, TsifyFieldAttrs},
+    container::Container,
+};
+
+/// The `#[tsify(patch)]` type: the struct with every field optional, serialized under the same
+/// names and left out when unset. It is declared as `{ a?: A; ... }`, and `ApplyPatch::apply` and
+/// `Ts::apply_patch` write just the fields it holds.
+pub fn expand(cont: &Container, name: &str) -> TokenStream {
+    let patch = match syn::parse_str::<syn::Ident>(name) {
+        Ok(patch) => patch,
+        Err(_) => {
+            cont.syn_error(syn::Error::new_spanned(
+                cont.ident(),
+                format!("#[tsify(patch = \"{name}\")] is not a valid type name"),
+            ));
+            return TokenStream::new();
+        }
+    };
+
+    let Data::Struct(_, fields) = cont.serde_data() else {
+        return TokenStream::new();
+    };
+    // A field with its own `deserialize_with` would be read differently in the patch, if at all.
+    let fields: Vec<_> = fields
+        .iter()
+        .filter(|field| is_written(field) && custom_deserialize_with(field).is_none())
+        .collect();
+
+    let ident = cont.ident();
+    let vis = &cont.serde_container.original.vis;
+    let generics = cont.generics();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let serde_path = match cont.serde_container.attrs.custom_serde_path() {
+        Some(path) => path.to_token_stream(),
+        None => quote!(serde),
+    };
+    let serde_crate = cont.serde_container.attrs.custom_serde_path().map(|path| {
+        let path = path.to_token_stream().to_string().replace(' ', "");
+        quote!(#[serde(crate = #path)])
+    });
+
+    let doc = format!(" A patch of `{ident}`: the fields to change, each one optional.");
+    let apply_doc = " Sets the fields that `patch` holds, as `ApplyPatch::apply` does.";
+    let tsify_config = expand_tsify_config(cont);
+    let patch_fields = fields.iter().map(|field| expand_field(field));
+    let members: Vec<_> = fields.iter().map(|field| &field.member).collect();
+
+    let patchable = cfg!(feature = "wasm-bindgen").then(|| expand_patchable(cont, &fields));
+
+    quote! {
+        #[doc = #doc]
+        #[derive(#serde_path::Serialize, #serde_path::Deserialize, tsify::Tsify)]
+        #serde_crate
+        #tsify_config
+        #vis struct #patch #generics #where_clause {
+            #(#patch_fields)*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::default::Default for #patch #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#members: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics tsify::ApplyPatch for #ident #ty_generics #where_clause {
+            type Patch = #patch #ty_generics;
+
+            fn apply(&mut self, patch: Self::Patch) {
+                #(
+                    if let ::core::option::Option::Some(value) = patch.#members {
+                        self.#members = value;
+                    }
+                )*
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #apply_doc]
+            #vis fn apply(&mut self, patch: #patch #ty_generics) {
+                <Self as tsify::ApplyPatch>::apply(self, patch)
+            }
+        }
+
+        #patchable
+    }
+}
+
+/// The options of the container that decide how the patch is serialized and declared.
+pub(crate) fn expand_tsify_config(cont: &Container) -> TokenStream {
+    let config = &cont.attrs.ty_config;
+
+    let backend = match config.backend {
+        Backend::Json => "json",
+        Backend::Js => "js",
+    };
+    let mut options = vec![quote!(backend = #backend)];
+
+    for (enabled, option) in [
+        (config.missing_as_null, quote!(missing_as_null)),
+        (config.hashmap_as_object, quote!(hashmap_as_object)),
+        (
+            config.large_number_types_as_bigints,
+            quote!(large_number_types_as_bigints),
+        ),
+        (config.bytes_as_arrays, quote!(bytes_as_arrays)),
+        (config.strip_rust_docs, quote!(strip_rust_docs)),
+    ] {
+        if enabled {
+            options.push(option);
+        }
+    }
+    if let Some(prefix) = &config.type_prefix {
+        options.push(quote!(type_prefix = #prefix));
+    }
+    if let Some(suffix) = &config.type_suffix {
+        options.push(quote!(type_suffix = #suffix));
+    }
+
+    quote!(#[tsify(#(#options),*)])
+}
+
+fn expand_field(field: &Field) -> TokenStream {
+    let original = field.original;
+    let docs = original
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"));
+    let vis = &original.vis;
+    let member = &field.member;
+    let ty = field.ty;
+
+    let serialize = field.attrs.name().serialize_name();
+    let deserialize = field.attrs.name().deserialize_name();
+    let aliases = field
+        .attrs
+        .aliases()
+        .iter()
+        .filter(|alias| *alias != deserialize);
+
+    // A malformed attribute is reported by the parser already.
+    let type_override = TsifyFieldAttrs::from_serde_field(field)
+        .ok()
+        .and_then(|attrs| attrs.type_override)
+        .map(|ty| quote!(#[tsify(type = #ty)]));
+
+    // serde reads `null` as a missing `Option`, so a field that is optional itself needs to keep a
+    // present `null` apart from a missing one, to clear the field rather than leave it.
+    let double_option = last_ident_is(ty, "Option")
+        .then(|| quote!(default, deserialize_with = "tsify::fields::double_option",));
+
+    quote! {
+        #(#docs)*
+        #[serde(
+            rename(serialize = #serialize, deserialize = #deserialize),
+            #(alias = #aliases,)*
+            #double_option
+            skip_serializing_if = "Option::is_none"
+        )]
+        #type_override
+        #vis #member: ::core::option::Option<#ty>,
+    }
+}
+
+fn expand_patchable(cont: &Container, fields: &[&Field]) -> TokenStream {
+    let ident = cont.ident();
+    let mut generics = cont.generics_without_defaults();
+
+    let serde_path = cont.serde_container.attrs.serde_path();
+    let bounds: Vec<syn::WherePredicate> = generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            parse_quote!(#ident: #serde_path::Serialize)
+        })
+        .collect();
+    generics.make_where_clause().predicates.extend(bounds);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let use_serde = match cont.serde_container.attrs.custom_serde_path() {
+        Some(path) => quote!(use #path as _serde;),
+        None => quote!(
+            extern crate serde as _serde;
+        ),
+    };
+
+    let writes = fields.iter().map(|field| {
+        let member = &field.member;
+        let write = expand_write(
+            field,
+            &quote!(js),
+            &quote!(value),
+            &quote!(config),
+            &quote!(Self),
+        );
+
+        quote! {
+            if let ::core::option::Option::Some(value) = &patch.#member {
+                (#write)?;
+            }
+        }
+    });
+
+    quote! {
+        const _: () = {
+            #use_serde
+
+            #[automatically_derived]
+            impl #impl_generics tsify::Patchable for #ident #ty_generics #where_clause {
+                fn write_patch(
+                    js: &wasm_bindgen::JsValue,
+                    patch: &Self::Patch,
+                    config: &tsify::SerializationConfig,
+                ) -> ::core::result::Result<(), tsify::Error> {
+                    #(#writes)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        };
+    }
+}
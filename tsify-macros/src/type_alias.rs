@@ -1,16 +1,41 @@
+use std::collections::HashSet;
+
 use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    attrs::TypeGenerationConfig, comments::extract_doc_comments, decl::TsTypeAliasDecl,
-    error_tracker::ErrorTracker, typescript::TsType,
+    attrs::TypeGenerationConfig,
+    comments::extract_doc_comments,
+    decl::TsTypeAliasDecl,
+    error_tracker::ErrorTracker,
+    parser::{linkable_types, unsupported_type_error},
+    typescript::{unsupported_types, TsType},
 };
 
 /// Expand a `#[declare]` macro on a Rust `type = ...` expression.
 pub fn expand(item: syn::ItemType) -> syn::Result<TokenStream> {
     let errors = ErrorTracker::new();
 
-    let type_ann = TsType::from_syn_type(&TypeGenerationConfig::default(), item.ty.as_ref());
+    let config = TypeGenerationConfig::default();
+    let type_ann = TsType::from_syn_type(&config, item.ty.as_ref());
+
+    if cfg!(feature = "strict") {
+        for ty in unsupported_types(item.ty.as_ref()) {
+            errors.syn_error(unsupported_type_error(ty));
+        }
+    }
+
+    let params = item
+        .generics
+        .type_params()
+        .map(|ty| ty.ident.to_string())
+        .collect::<HashSet<_>>();
+    let mut linkable = HashSet::from([item.ident.to_string()]);
+    linkable.extend(
+        linkable_types(&config, item.ty.as_ref())
+            .into_iter()
+            .filter(|name| !params.contains(name)),
+    );
 
     let decl = TsTypeAliasDecl {
         id: item.ident.to_string(),
@@ -21,7 +46,7 @@ pub fn expand(item: syn::ItemType) -> syn::Result<TokenStream> {
             .map(|ty| ty.ident.to_string())
             .collect(),
         type_ann,
-        comments: extract_doc_comments(&item.attrs),
+        comments: extract_doc_comments(&item.attrs, &config, &linkable),
     };
 
     let decl_str = decl.to_string();
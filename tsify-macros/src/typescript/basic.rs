@@ -30,10 +30,20 @@ pub struct TsTypeElement {
     pub key: String,
     pub type_ann: TsType,
     pub optional: bool,
+    pub readonly: bool,
     pub comments: Vec<String>,
 }
 
 impl TsTypeElement {
+    /// Mark this member readonly, along with everything reachable from its type.
+    pub fn into_readonly(self) -> Self {
+        Self {
+            readonly: true,
+            type_ann: self.type_ann.into_readonly(),
+            ..self
+        }
+    }
+
     pub fn to_string_with_indent(&self, indent: usize) -> String {
         let out = self.to_string();
         let indent_str = " ".repeat(indent);
@@ -53,6 +63,10 @@ impl Display for TsTypeElement {
 
         write_doc_comments(f, &self.comments)?;
 
+        if self.readonly {
+            write!(f, "readonly ")?;
+        }
+
         if is_js_ident(key) {
             write!(f, "{key}{optional_ann}: {type_ann}")
         } else {
@@ -73,6 +87,27 @@ impl From<TsTypeElement> for TsType {
     }
 }
 
+/// A parameter of a function type, e.g. `name: string`, `context?: number` or `...rest: T[]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsFnParam {
+    /// The parameter name, `arg{index}` if not known.
+    pub name: Option<String>,
+    pub type_ann: TsType,
+    pub optional: bool,
+    pub rest: bool,
+}
+
+impl From<TsType> for TsFnParam {
+    fn from(type_ann: TsType) -> Self {
+        Self {
+            name: None,
+            type_ann,
+            optional: false,
+            rest: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TsTypeLit {
     pub members: Vec<TsTypeElement>,
@@ -134,7 +169,7 @@ pub enum NullType {
 
 impl NullType {
     pub const fn new(config: &TypeGenerationConfig) -> Self {
-        if cfg!(feature = "js") && !config.missing_as_null {
+        if config.is_js() && !config.missing_as_null {
             Self::Undefined
         } else {
             Self::Null
@@ -149,7 +184,7 @@ impl NullType {
     }
 }
 
-fn is_js_ident(string: &str) -> bool {
+pub fn is_js_ident(string: &str) -> bool {
     !string.is_empty()
         && !string.starts_with(|c: char| c.is_ascii_digit())
         && !string.contains(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '$')
@@ -5,3 +5,4 @@ mod ts_type_from_name;
 
 pub use basic::*;
 pub use ts_type::*;
+pub use ts_type_from_name::is_tsify_path;
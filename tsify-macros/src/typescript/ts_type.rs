@@ -4,7 +4,7 @@ use serde_derive_internals::{ast::Style, attr::TagType};
 
 use crate::attrs::TypeGenerationConfig;
 
-use super::{NullType, TsKeywordTypeKind, TsTypeElement, TsTypeLit};
+use super::{NullType, TsFnParam, TsKeywordTypeKind, TsTypeElement, TsTypeLit};
 
 /// A Typescript type
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,6 +17,10 @@ pub enum TsType {
     Array(Box<Self>),
     /// A tuple type like `[number, string]`, `[number, string, boolean]`, etc.
     Tuple(Vec<Self>),
+    /// A tuple type with labelled elements like `[x: number, y?: number]`.
+    LabelledTuple(Vec<TsTypeElement>),
+    /// A readonly array or tuple like `readonly number[]`, `readonly [number, string]`, etc.
+    Readonly(Box<Self>),
     /// An optional type along with how a missing value is represented (i.e., as `undefined` or `null`).
     Option(Box<Self>, NullType),
     /// A reference to a type like `Foo`, `Bar<T>`, etc.
@@ -24,9 +28,9 @@ pub enum TsType {
         name: String,
         type_params: Vec<Self>,
     },
-    /// A function type like `(arg0: string, arg1: number) => void`
+    /// A function type like `(name: string, arg1: number) => void`
     Fn {
-        params: Vec<Self>,
+        params: Vec<TsFnParam>,
         type_ann: Box<Self>,
     },
     /// A type literal like `{ foo: number; bar: string; }`
@@ -70,6 +74,56 @@ impl TsType {
         matches!(self, Self::Ref { .. })
     }
 
+    /// The parameters of this function type, or of the function type it wraps.
+    pub fn fn_params_mut(&mut self) -> Option<&mut Vec<TsFnParam>> {
+        match self {
+            TsType::Fn { params, .. } => Some(params),
+            TsType::Option(ty, _) => ty.fn_params_mut(),
+            // `dyn Fn(..)` becomes an intersection of its bounds.
+            TsType::Intersection(tys) => tys.iter_mut().find_map(TsType::fn_params_mut),
+            _ => None,
+        }
+    }
+
+    /// Make every array, tuple and type literal member reachable from this type readonly.
+    /// Function types are left as they are.
+    pub fn into_readonly(self) -> Self {
+        match self {
+            TsType::Array(t) => {
+                TsType::Readonly(Box::new(TsType::Array(Box::new(t.into_readonly()))))
+            }
+            TsType::Tuple(tv) => TsType::Readonly(Box::new(TsType::Tuple(
+                tv.into_iter().map(TsType::into_readonly).collect(),
+            ))),
+            TsType::LabelledTuple(elems) => TsType::Readonly(Box::new(TsType::LabelledTuple(
+                elems
+                    .into_iter()
+                    .map(|elem| TsTypeElement {
+                        type_ann: elem.type_ann.into_readonly(),
+                        ..elem
+                    })
+                    .collect(),
+            ))),
+            TsType::Option(t, null) => TsType::Option(Box::new(t.into_readonly()), null),
+            TsType::Ref { name, type_params } => TsType::Ref {
+                name,
+                type_params: type_params.into_iter().map(TsType::into_readonly).collect(),
+            },
+            TsType::TypeLit(lit) => TsType::TypeLit(TsTypeLit {
+                members: lit
+                    .members
+                    .into_iter()
+                    .map(TsTypeElement::into_readonly)
+                    .collect(),
+            }),
+            TsType::Intersection(tv) => {
+                TsType::Intersection(tv.into_iter().map(TsType::into_readonly).collect())
+            }
+            TsType::Union(tv) => TsType::Union(tv.into_iter().map(TsType::into_readonly).collect()),
+            _ => self,
+        }
+    }
+
     pub fn and(self, other: Self) -> Self {
         match (self, other) {
             (TsType::TypeLit(x), TsType::TypeLit(y)) => x.and(y).into(),
@@ -125,7 +179,14 @@ impl TsType {
             BareFn(TypeBareFn { inputs, output, .. }) => {
                 let params = inputs
                     .iter()
-                    .map(|arg| Self::from_syn_type(config, &arg.ty))
+                    .map(|arg| TsFnParam {
+                        name: arg
+                            .name
+                            .as_ref()
+                            .map(|(name, _)| name.to_string())
+                            .filter(|name| name != "_"),
+                        ..TsFnParam::from(Self::from_syn_type(config, &arg.ty))
+                    })
                     .collect();
 
                 let type_ann = if let syn::ReturnType::Type(_, ty) = output {
@@ -156,15 +217,19 @@ impl TsType {
 
             TraitObject(TypeTraitObject { bounds, .. })
             | ImplTrait(TypeImplTrait { bounds, .. }) => {
-                let elems = bounds
+                let mut elems = bounds
                     .iter()
                     .filter_map(|t| match t {
                         TypeParamBound::Trait(t) => Self::from_path(config, &t.path),
                         _ => None, // skip lifetime etc.
                     })
-                    .collect();
+                    .collect::<Vec<_>>();
 
-                Self::Intersection(elems)
+                if elems.len() == 1 {
+                    elems.remove(0)
+                } else {
+                    Self::Intersection(elems)
+                }
             }
 
             Ptr(_) | Infer(_) | Macro(_) | Never(_) | Verbatim(_) => TsType::NEVER,
@@ -176,6 +241,10 @@ impl TsType {
     /// Convert a `syn::Path` to a `TsType`. For example `core::option::Option<i32>` would be
     /// converted to `Self::Option(number)`.
     fn from_path(config: &TypeGenerationConfig, path: &syn::Path) -> Option<Self> {
+        if let Some(ty) = Self::from_ts_path(config, path) {
+            return Some(ty);
+        }
+
         path.segments
             .last()
             .map(|segment| Self::from_terminal_path_segment(config, segment))
@@ -239,6 +308,7 @@ impl TsType {
                         key: name,
                         type_ann,
                         optional: false,
+                        readonly: false,
                         comments: vec![],
                     }
                     .into()
@@ -250,6 +320,7 @@ impl TsType {
                         key: tag.clone(),
                         type_ann: TsType::Lit(name),
                         optional: false,
+                        readonly: false,
                         comments: vec![],
                     }
                     .into();
@@ -260,6 +331,7 @@ impl TsType {
                         key: tag.clone(),
                         type_ann: TsType::Lit(name),
                         optional: false,
+                        readonly: false,
                         comments: vec![],
                     }
                     .into();
@@ -272,6 +344,7 @@ impl TsType {
                     key: tag.clone(),
                     type_ann: TsType::Lit(name),
                     optional: false,
+                    readonly: false,
                     comments: vec![],
                 };
 
@@ -282,6 +355,7 @@ impl TsType {
                         key: content.clone(),
                         type_ann,
                         optional: false,
+                        readonly: false,
                         comments: vec![],
                     };
 
@@ -302,7 +376,7 @@ impl TsType {
             TsType::Ref { type_params, .. } => {
                 type_params.iter().for_each(|t| t.visit(f));
             }
-            TsType::Array(elem) => elem.visit(f),
+            TsType::Array(elem) | TsType::Readonly(elem) => elem.visit(f),
             TsType::Tuple(elems) => {
                 elems.iter().for_each(|t| t.visit(f));
             }
@@ -310,10 +384,11 @@ impl TsType {
             TsType::Fn { params, type_ann } => {
                 params
                     .iter()
+                    .map(|p| &p.type_ann)
                     .chain(Some(type_ann.as_ref()))
                     .for_each(|t| t.visit(f));
             }
-            TsType::TypeLit(TsTypeLit { members }) => {
+            TsType::TypeLit(TsTypeLit { members }) | TsType::LabelledTuple(members) => {
                 members.iter().for_each(|m| m.type_ann.visit(f));
             }
             TsType::Intersection(tys) | TsType::Union(tys) => {
@@ -340,6 +415,9 @@ impl TsType {
     pub fn prefix_type_refs(self, prefix: &String, exceptions: &Vec<String>) -> Self {
         match self {
             TsType::Array(t) => TsType::Array(Box::new(t.prefix_type_refs(prefix, exceptions))),
+            TsType::Readonly(t) => {
+                TsType::Readonly(Box::new(t.prefix_type_refs(prefix, exceptions)))
+            }
             TsType::Tuple(tv) => TsType::Tuple(
                 tv.iter()
                     .map(|t| t.clone().prefix_type_refs(prefix, exceptions))
@@ -369,8 +447,11 @@ impl TsType {
             }
             TsType::Fn { params, type_ann } => TsType::Fn {
                 params: params
-                    .iter()
-                    .map(|t| t.clone().prefix_type_refs(prefix, exceptions))
+                    .into_iter()
+                    .map(|p| TsFnParam {
+                        type_ann: p.type_ann.prefix_type_refs(prefix, exceptions),
+                        ..p
+                    })
                     .collect(),
                 type_ann: Box::new(type_ann.prefix_type_refs(prefix, exceptions)),
             },
@@ -381,11 +462,21 @@ impl TsType {
                     .map(|t| TsTypeElement {
                         key: t.key.clone(),
                         optional: t.optional,
+                        readonly: t.readonly,
                         type_ann: t.type_ann.clone().prefix_type_refs(prefix, exceptions),
                         comments: t.comments.clone(),
                     })
                     .collect(),
             }),
+            TsType::LabelledTuple(elems) => TsType::LabelledTuple(
+                elems
+                    .into_iter()
+                    .map(|t| TsTypeElement {
+                        type_ann: t.type_ann.prefix_type_refs(prefix, exceptions),
+                        ..t
+                    })
+                    .collect(),
+            ),
             TsType::Intersection(tv) => TsType::Intersection(
                 tv.iter()
                     .map(|t| t.clone().prefix_type_refs(prefix, exceptions))
@@ -402,7 +493,7 @@ impl TsType {
 
     pub fn type_refs(&self, type_refs: &mut Vec<(String, Vec<TsType>)>) {
         match self {
-            TsType::Array(t) | TsType::Option(t, _) => t.type_refs(type_refs),
+            TsType::Array(t) | TsType::Readonly(t) | TsType::Option(t, _) => t.type_refs(type_refs),
             TsType::Tuple(tv) | TsType::Union(tv) | TsType::Intersection(tv) => {
                 tv.iter().for_each(|t| t.type_refs(type_refs))
             }
@@ -413,11 +504,11 @@ impl TsType {
                     .for_each(|t| t.clone().type_refs(type_refs));
             }
             TsType::Fn { params, type_ann } => {
-                params.iter().for_each(|t| t.clone().type_refs(type_refs));
+                params.iter().for_each(|p| p.type_ann.type_refs(type_refs));
                 type_ann.type_refs(type_refs);
             }
-            TsType::TypeLit(lit) => {
-                lit.members.iter().for_each(|t| {
+            TsType::TypeLit(TsTypeLit { members }) | TsType::LabelledTuple(members) => {
+                members.iter().for_each(|t| {
                     t.type_ann.type_refs(type_refs);
                 });
             }
@@ -426,6 +517,138 @@ impl TsType {
     }
 }
 
+/// Call `f` on `ty` and on every type nested in it, in the order [`TsType::from_syn_type`]
+/// visits them.
+pub fn visit_syn_type<'a, F: FnMut(&'a syn::Type)>(ty: &'a syn::Type, f: &mut F) {
+    fn visit_path<'a, F: FnMut(&'a syn::Type)>(path: &'a syn::Path, f: &mut F) {
+        let Some(segment) = path.segments.last() else {
+            return;
+        };
+
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(path) => {
+                path.args.iter().for_each(|arg| match arg {
+                    syn::GenericArgument::Type(t) => visit_syn_type(t, f),
+                    syn::GenericArgument::AssocType(t) => visit_syn_type(&t.ty, f),
+                    _ => (),
+                })
+            }
+            syn::PathArguments::Parenthesized(path) => {
+                path.inputs.iter().for_each(|t| visit_syn_type(t, f));
+                if let syn::ReturnType::Type(_, t) = &path.output {
+                    visit_syn_type(t, f);
+                }
+            }
+            syn::PathArguments::None => (),
+        }
+    }
+
+    use syn::Type::*;
+
+    f(ty);
+
+    match ty {
+        Array(syn::TypeArray { elem, .. })
+        | Slice(syn::TypeSlice { elem, .. })
+        | Reference(syn::TypeReference { elem, .. })
+        | Paren(syn::TypeParen { elem, .. })
+        | Group(syn::TypeGroup { elem, .. }) => visit_syn_type(elem, f),
+
+        BareFn(syn::TypeBareFn { inputs, output, .. }) => {
+            inputs.iter().for_each(|arg| visit_syn_type(&arg.ty, f));
+            if let syn::ReturnType::Type(_, t) = output {
+                visit_syn_type(t, f);
+            }
+        }
+
+        Tuple(syn::TypeTuple { elems, .. }) => elems.iter().for_each(|t| visit_syn_type(t, f)),
+
+        Path(syn::TypePath { path, .. }) => visit_path(path, f),
+
+        TraitObject(syn::TypeTraitObject { bounds, .. })
+        | ImplTrait(syn::TypeImplTrait { bounds, .. }) => bounds.iter().for_each(|t| {
+            if let syn::TypeParamBound::Trait(t) = t {
+                visit_path(&t.path, f);
+            }
+        }),
+
+        _ => (),
+    }
+}
+
+/// Collect the parts of `ty` that [`TsType::from_syn_type`] can only represent as `never`
+/// because there is no Typescript equivalent.
+pub fn unsupported_types(ty: &syn::Type) -> Vec<&syn::Type> {
+    use syn::Type::*;
+
+    let mut out = Vec::new();
+    visit_syn_type(ty, &mut |ty| match ty {
+        Array(_) | Slice(_) | Reference(_) | Paren(_) | Group(_) | BareFn(_) | Tuple(_)
+        | TraitObject(_) | ImplTrait(_) => (),
+        Path(syn::TypePath { path, .. }) if !path.segments.is_empty() => (),
+        // `!` really is `never`.
+        Never(_) => (),
+        _ => out.push(ty),
+    });
+    out
+}
+
+/// Collect the paths in `ty` that [`TsType::from_syn_type`] turns into a reference to another
+/// declaration rather than a built-in Typescript type, e.g. `Address` in `Vec<Address>`.
+///
+/// Traits are not types, so `dyn Trait` and `impl Trait` are left out along with their generic
+/// arguments, except for the parameters and return type of an `Fn` bound, which do end up in
+/// the declaration.
+pub fn referenced_types<'a>(
+    config: &TypeGenerationConfig,
+    ty: &'a syn::Type,
+) -> Vec<&'a syn::TypePath> {
+    let mut out = Vec::new();
+    let mut in_trait_args: Vec<&'a syn::Type> = Vec::new();
+    visit_syn_type(ty, &mut |ty| {
+        if in_trait_args.iter().any(|t| std::ptr::eq(*t, ty)) {
+            return;
+        }
+
+        if let syn::Type::TraitObject(syn::TypeTraitObject { bounds, .. })
+        | syn::Type::ImplTrait(syn::TypeImplTrait { bounds, .. }) = ty
+        {
+            for bound in bounds {
+                let syn::TypeParamBound::Trait(bound) = bound else {
+                    continue;
+                };
+                let Some(segment) = bound.path.segments.last() else {
+                    continue;
+                };
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        let arg = match arg {
+                            syn::GenericArgument::Type(t) => t,
+                            syn::GenericArgument::AssocType(t) => &t.ty,
+                            _ => continue,
+                        };
+                        visit_syn_type(arg, &mut |t| in_trait_args.push(t));
+                    }
+                }
+            }
+        }
+
+        let syn::Type::Path(type_path) = ty else {
+            return;
+        };
+        let Some(segment) = type_path.path.segments.last() else {
+            return;
+        };
+
+        if let Some(TsType::Ref { name, .. }) = TsType::from_path(config, &type_path.path) {
+            if name == config.format_name(segment.ident.to_string()) {
+                out.push(type_path);
+            }
+        }
+    });
+    out
+}
+
 fn parse_len(expr: &syn::Expr) -> Option<usize> {
     if let syn::Expr::Lit(syn::ExprLit {
         lit: syn::Lit::Int(lit_int),
@@ -1,6 +1,6 @@
 use crate::attrs::TypeGenerationConfig;
 
-use super::TsType;
+use super::{unsupported_types, TsType};
 
 macro_rules! assert_ts {
         ($config:expr, $( $t:ty )|* , $expected:expr) => {
@@ -44,6 +44,12 @@ fn test_basic_types() {
     assert_ts!(config, Result<i32, String>, "{ Ok: number } | { Err: string }");
     assert_ts!(config, dyn Fn(String, f64) | dyn FnOnce(String, f64) | dyn FnMut(String, f64), "(arg0: string, arg1: number) => void");
     assert_ts!(config, dyn Fn(String) -> i32 | dyn FnOnce(String) -> i32 | dyn FnMut(String) -> i32, "(arg0: string) => number");
+    assert_ts!(
+        config,
+        fn(name: String, _: i32) -> bool,
+        "(name: string, arg1: number) => boolean"
+    );
+    assert_ts!(config, Vec<fn(u8)>, "((arg0: number) => void)[]");
 
     assert_ts!(config, (i32), "number");
     assert_ts!(config, (i32, String, bool), "[number, string, boolean]");
@@ -76,3 +82,42 @@ fn test_basic_types() {
         "{ start: number; end: number }"
     );
 }
+
+#[test]
+fn test_ts_types() {
+    let config = TypeGenerationConfig::default();
+
+    assert_ts!(config, Ts<Meta> | tsify::Ts<Meta> | Ts<Meta, AsDeclared>, "Meta");
+    assert_ts!(
+        config,
+        Ts<Meta, AsObject> | tsify::Ts<Meta, tsify::AsObject>,
+        "TsifyAsObject<Meta>"
+    );
+
+    // Only tsify's own `Ts` and `AsObject` are unwrapped.
+    assert_ts!(config, crate::Ts<Meta>, "Ts<Meta>");
+    assert_ts!(config, other::Ts<Meta, Extra>, "Ts<Meta, Extra>");
+    assert_ts!(config, Ts<Meta, other::AsObject>, "Meta");
+}
+
+#[test]
+fn test_unsupported_types() {
+    fn unsupported(ty: syn::Type) -> Vec<String> {
+        unsupported_types(&ty)
+            .into_iter()
+            .map(|ty| quote::ToTokens::to_token_stream(ty).to_string())
+            .collect()
+    }
+
+    assert!(unsupported(syn::parse_quote!(HashMap<String, Vec<Option<u8>>>)).is_empty());
+    assert!(unsupported(syn::parse_quote!(dyn Fn(String) -> !)).is_empty());
+    assert_eq!(unsupported(syn::parse_quote!(*const u8)), ["* const u8"]);
+    assert_eq!(
+        unsupported(syn::parse_quote!(Result<[*mut u8; 2], (_, String)>)),
+        ["* mut u8", "_"]
+    );
+    assert_eq!(
+        unsupported(syn::parse_quote!(Box<dyn Fn(foo!()) -> *const u8>)),
+        ["foo ! ()", "* const u8"]
+    );
+}
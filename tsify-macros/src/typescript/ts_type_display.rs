@@ -15,12 +15,20 @@ impl Display for TsType {
             }
 
             TsType::Array(elem) => match elem.as_ref() {
-                TsType::Union(_) | TsType::Intersection(_) | &TsType::Option(_, _) => {
+                TsType::Union(_)
+                | TsType::Intersection(_)
+                | &TsType::Option(_, _)
+                | TsType::Readonly(_)
+                | TsType::Fn { .. } => {
                     write!(f, "({elem})[]")
                 }
                 _ => write!(f, "{elem}[]"),
             },
 
+            TsType::Readonly(elem) => {
+                write!(f, "readonly {elem}")
+            }
+
             TsType::Tuple(elems) => {
                 let elems = elems
                     .iter()
@@ -31,6 +39,27 @@ impl Display for TsType {
                 write!(f, "[{elems}]")
             }
 
+            TsType::LabelledTuple(elems) => {
+                if elems.iter().all(|elem| elem.comments.is_empty()) {
+                    let elems = elems
+                        .iter()
+                        .map(|elem| elem.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    return write!(f, "[{elems}]");
+                }
+
+                // Doc comments span several lines, so put each element on its own line.
+                let elems = elems
+                    .iter()
+                    .map(|elem| format!("\n{},", elem.to_string_with_indent(4)))
+                    .collect::<Vec<_>>()
+                    .join("");
+
+                write!(f, "[{elems}\n]")
+            }
+
             TsType::Ref { name, type_params } => {
                 let params = type_params
                     .iter()
@@ -49,16 +78,26 @@ impl Display for TsType {
                 let params = params
                     .iter()
                     .enumerate()
-                    .map(|(i, param)| format!("arg{i}: {param}"))
+                    .map(|(i, param)| {
+                        let rest = if param.rest { "..." } else { "" };
+                        let optional = if param.optional { "?" } else { "" };
+                        let type_ann = &param.type_ann;
+
+                        match &param.name {
+                            Some(name) => format!("{rest}{name}{optional}: {type_ann}"),
+                            None => format!("{rest}arg{i}{optional}: {type_ann}"),
+                        }
+                    })
                     .collect::<Vec<_>>()
                     .join(", ");
 
                 write!(f, "({params}) => {type_ann}")
             }
 
-            TsType::Option(elem, null) => {
-                write!(f, "{elem} | {}", null.to_type())
-            }
+            TsType::Option(elem, null) => match elem.as_ref() {
+                TsType::Fn { .. } => write!(f, "({elem}) | {}", null.to_type()),
+                _ => write!(f, "{elem} | {}", null.to_type()),
+            },
 
             TsType::TypeLit(type_lit) => {
                 write!(f, "{type_lit}")
@@ -73,7 +112,9 @@ impl Display for TsType {
                 let types = types
                     .iter()
                     .map(|ty| match ty {
-                        TsType::Union(_) => format!("({ty})"),
+                        TsType::Union(_) | TsType::Option(_, _) | TsType::Fn { .. } => {
+                            format!("({ty})")
+                        }
                         TsType::TypeLit(tl) => {
                             // Intersections are formatted as single lines, so we need to remove
                             // any comments as they are multi-line and will break the formatting.
@@ -103,7 +144,7 @@ impl Display for TsType {
                     let types = types
                         .iter()
                         .map(|ty| match ty {
-                            TsType::Intersection(_) => format!("({ty})"),
+                            TsType::Intersection(_) | TsType::Fn { .. } => format!("({ty})"),
                             _ => ty.to_string(),
                         })
                         .collect::<Vec<_>>()
@@ -13,6 +13,7 @@ macro_rules! type_lit {
                     key: stringify!($k).to_string(),
                     type_ann: $t,
                     optional: false,
+                    readonly: false,
                     comments: vec![],
                 }
             ),*],
@@ -32,7 +33,7 @@ impl TsType {
             "u8" | "u16" | "u32" | "i8" | "i16" | "i32" | "f64" | "f32" => Self::NUMBER,
 
             "usize" | "isize" | "u64" | "i64" => {
-                if cfg!(feature = "js") && config.large_number_types_as_bigints {
+                if config.is_js() && config.large_number_types_as_bigints {
                     Self::BIGINT
                 } else {
                     Self::NUMBER
@@ -40,7 +41,7 @@ impl TsType {
             }
 
             "u128" | "i128" => {
-                if cfg!(feature = "js") {
+                if config.is_js() {
                     Self::BIGINT
                 } else {
                     Self::NUMBER
@@ -66,7 +67,7 @@ impl TsType {
                     .map(|arg| Self::from_syn_type(config, arg))
                     .collect();
 
-                let name = if cfg!(feature = "js") && !config.hashmap_as_object {
+                let name = if config.is_js() && !config.hashmap_as_object {
                     "Map"
                 } else {
                     "Record"
@@ -87,7 +88,7 @@ impl TsType {
             ),
 
             "ByteBuf" => {
-                if cfg!(feature = "js") {
+                if config.is_js() && !config.bytes_as_arrays {
                     Self::Ref {
                         name: String::from("Uint8Array"),
                         type_params: vec![],
@@ -132,7 +133,7 @@ impl TsType {
             "Fn" | "FnOnce" | "FnMut" => {
                 let params = args
                     .into_iter()
-                    .map(|ty| Self::from_syn_type(config, ty))
+                    .map(|ty| Self::from_syn_type(config, ty).into())
                     .collect();
                 let type_ann = fn_output
                     .map(|ty| Self::from_syn_type(config, ty))
@@ -156,3 +157,52 @@ impl TsType {
         }
     }
 }
+
+/// Whether `path` names `tsify::#ident`, written either in full or bare, as imported with `use
+/// tsify::Ts`. As with the names `TsType::from_name` maps, a bare name is all there is to go on, so a type of
+/// the crate's own called `Ts` or `AsObject` has to be written as a longer path, e.g.
+/// `crate::Ts`, not to be taken for tsify's.
+pub fn is_tsify_path(path: &syn::Path, ident: &str) -> bool {
+    let segments: Vec<_> = path.segments.iter().collect();
+    let is_ident = |segment: &syn::PathSegment| segment.ident == ident;
+    match segments.as_slice() {
+        [segment] => path.leading_colon.is_none() && is_ident(segment),
+        [krate, segment] => {
+            krate.ident == "tsify" && krate.arguments.is_none() && is_ident(segment)
+        }
+        _ => false,
+    }
+}
+
+impl TsType {
+    /// The type of a `tsify::Ts` field, which holds the JS value as is. `None` for any other path.
+    pub(super) fn from_ts_path(config: &TypeGenerationConfig, path: &syn::Path) -> Option<Self> {
+        if !is_tsify_path(path, "Ts") {
+            return None;
+        }
+
+        let syn::PathArguments::AngleBracketed(args) = &path.segments.last()?.arguments else {
+            return None;
+        };
+        let args: Vec<_> = args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect();
+
+        match args.as_slice() {
+            [ty] => Some(Self::from_syn_type(config, ty)),
+            [ty, syn::Type::Path(c)] if c.qself.is_none() && is_tsify_path(&c.path, "AsObject") => {
+                Some(Self::Ref {
+                    name: String::from("TsifyAsObject"),
+                    type_params: vec![Self::from_syn_type(config, ty)],
+                })
+            }
+            [ty, _] => Some(Self::from_syn_type(config, ty)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_derive_internals::{
+    ast::{Data, Field, Style, Variant},
+    attr::{self, TagType},
+};
+use syn::parse_quote;
+
+use crate::{
+    attrs::{custom_deserialize_with, TsifyFieldAttrs},
+    container::Container,
+};
+
+/// Implement `tsify::Validate` for `#[tsify(validate)]`. The checks follow serde's layout of the
+/// type rather than the Typescript declaration, so that a value passes when `to_rust` would find
+/// every field and variant where it expects them. The exceptions are listed on `Validate`.
+pub fn expand(cont: &Container) -> TokenStream {
+    let ident = cont.ident();
+    let mut generics = cont.generics_without_defaults();
+
+    let bounds: Vec<syn::WherePredicate> = generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            parse_quote!(#ident: tsify::Validate)
+        })
+        .collect();
+    generics.make_where_clause().predicates.extend(bounds);
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let body = expand_container(cont);
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics tsify::Validate for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn validate(
+                js: &wasm_bindgen::JsValue,
+                backend: tsify::Backend,
+            ) -> ::core::result::Result<(), tsify::Mismatch> {
+                use tsify::validate as __v;
+                #body
+            }
+        }
+    }
+}
+
+fn expand_container(cont: &Container) -> TokenStream {
+    let serde_attrs = cont.serde_attrs();
+
+    if let Some(from) = serde_attrs.type_from().or(serde_attrs.type_try_from()) {
+        return quote!(<#from as tsify::Validate>::validate(js, backend));
+    }
+
+    // The declaration is written by hand, so there is nothing to check against.
+    if cont.attrs.type_override.is_some() {
+        return quote!(::core::result::Result::Ok(()));
+    }
+
+    let name = cont.ident().to_string();
+
+    match cont.serde_data() {
+        Data::Struct(_, fields) if cont.transparent() => {
+            match fields.iter().find(|field| field.attrs.transparent()) {
+                Some(field) => expand_value(field),
+                None => quote!(::core::result::Result::Ok(())),
+            }
+        }
+        Data::Struct(style, fields) => {
+            let container_default = !serde_attrs.default().is_none();
+            let deny_unknown_fields = serde_attrs.deny_unknown_fields();
+            expand_fields(
+                *style,
+                fields,
+                container_default,
+                deny_unknown_fields,
+                Layout::Direct,
+                "struct",
+                &name,
+            )
+        }
+        Data::Enum(variants) => expand_enum(serde_attrs, variants, &name),
+    }
+}
+
+/// Whether serde deserializes the field some way tsify cannot see.
+pub(crate) fn is_custom(field: &Field) -> bool {
+    // A malformed attribute is reported by the parser already.
+    let type_override = match TsifyFieldAttrs::from_serde_field(field) {
+        Ok(attrs) => attrs.type_override.is_some(),
+        Err(_) => true,
+    };
+    type_override || custom_deserialize_with(field).is_some()
+}
+
+/// Check `js` as the type of `field`.
+fn expand_value(field: &Field) -> TokenStream {
+    if is_custom(field) {
+        return quote!(::core::result::Result::Ok(()));
+    }
+
+    let ty = field.ty;
+    quote!(<#ty as tsify::Validate>::validate(js, backend))
+}
+
+/// Where serde reads the fields of a struct or variant from, which decides the forms it accepts.
+#[derive(Clone, Copy)]
+enum Layout<'a> {
+    /// A struct, or a variant read straight from the backend. serde_json also reads a struct
+    /// from an array of its fields, serde-wasm-bindgen only from an object.
+    Direct,
+    /// The rest of an internally tagged object, without the property `tag`.
+    Internal { tag: &'a str },
+    /// An untagged variant, which serde buffers before reading it, from an array too.
+    Buffered,
+}
+
+/// Check `js` as the fields of a struct or variant, e.g. `struct Foo` or `variant Foo::A`.
+fn expand_fields(
+    style: Style,
+    fields: &[Field],
+    container_default: bool,
+    deny_unknown_fields: bool,
+    layout: Layout,
+    kind: &str,
+    name: &str,
+) -> TokenStream {
+    let fields: Vec<_> = fields
+        .iter()
+        .filter(|field| !field.attrs.skip_deserializing())
+        .collect();
+    let tag = match layout {
+        Layout::Internal { tag } => Some(tag),
+        Layout::Direct | Layout::Buffered => None,
+    };
+
+    match style {
+        Style::Struct => {
+            let expected = format!("{kind} {name}");
+            let has_default = |field: &Field| container_default || !field.attrs.default().is_none();
+            // A flattened field gets the properties that no other field claims, and with
+            // `deny_unknown_fields` there must be no others.
+            let siblings: Vec<&str> = fields
+                .iter()
+                .filter(|field| !field.attrs.flatten())
+                .flat_map(|field| {
+                    let name = field.attrs.name().deserialize_name();
+                    let aliases = field.attrs.aliases().iter().map(String::as_str);
+                    std::iter::once(name).chain(aliases.filter(move |alias| *alias != name))
+                })
+                .chain(tag)
+                .collect();
+            let checks = fields
+                .iter()
+                .filter(|field| !is_custom(field))
+                .map(|field| {
+                    let ty = field.ty;
+
+                    if field.attrs.flatten() {
+                        return quote!(__v::flatten::<#ty>(object, &[#(#siblings),*], backend)?;);
+                    }
+
+                    let name = field.attrs.name().deserialize_name();
+                    let aliases = field.attrs.aliases().iter().filter(|alias| *alias != name);
+                    let has_default = has_default(field);
+
+                    quote!(__v::field::<#ty>(object, &[#name #(, #aliases)*], #has_default, backend)?;)
+                });
+
+            let deny_unknown_fields = deny_unknown_fields
+                .then(|| quote!(__v::deny_unknown_fields(object, &[#(#siblings),*], backend)?;));
+
+            // serde reads a struct from an array of its fields in order, unless one is
+            // flattened, filling in the defaults of those missing at the end.
+            let reads_array = match layout {
+                _ if fields.iter().any(|field| field.attrs.flatten()) => None,
+                Layout::Direct => Some(quote!(!backend.is_js())),
+                Layout::Buffered => Some(quote!(true)),
+                Layout::Internal { .. } => None,
+            };
+            let array = reads_array.map(|reads_array| {
+                let len = fields.len() as u32;
+                let required = fields
+                    .iter()
+                    .rposition(|field| !has_default(field))
+                    .map_or(0, |index| index as u32 + 1);
+                let expected = match len {
+                    1 => format!("{expected} with 1 element"),
+                    len => format!("{expected} with {len} elements"),
+                };
+                let checks = fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, field)| !is_custom(field))
+                    .map(|(index, field)| {
+                        let ty = field.ty;
+                        let index = index as u32;
+                        quote!(__v::struct_element::<#ty>(&array, #index, backend)?;)
+                    });
+
+                quote! {
+                    if #reads_array {
+                        if let ::core::option::Option::Some(array) = __v::struct_array(js, #required, #len, #expected)? {
+                            #(#checks)*
+                            return ::core::result::Result::Ok(());
+                        }
+                    }
+                }
+            });
+
+            quote! {
+                #array
+                let object = __v::object(js, #expected)?;
+                #deny_unknown_fields
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+        Style::Tuple => {
+            let len = fields.len() as u32;
+            let expected = format!("tuple {kind} {name}");
+            let checks = fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| !is_custom(field))
+                .map(|(index, field)| {
+                    let ty = field.ty;
+                    let index = index as u32;
+                    quote!(__v::element::<#ty>(&array, #index, backend)?;)
+                });
+
+            quote! {
+                let array = __v::tuple(js, #len, #expected)?;
+                #(#checks)*
+                ::core::result::Result::Ok(())
+            }
+        }
+        Style::Newtype => match (fields.first(), tag) {
+            // serde hands the inner type the object without the tag, like a flattened field.
+            (Some(field), Some(tag)) if !is_custom(field) => {
+                let ty = field.ty;
+                quote!(__v::flatten::<#ty>(js, &[#tag], backend))
+            }
+            (Some(field), _) => expand_value(field),
+            (None, _) => quote!(::core::result::Result::Ok(())),
+        },
+        Style::Unit => {
+            let expected = format!("unit {kind} {name}");
+            quote!(__v::unit(js, #expected))
+        }
+    }
+}
+
+fn expand_enum(serde_attrs: &attr::Container, variants: &[Variant], name: &str) -> TokenStream {
+    let variants: Vec<_> = variants
+        .iter()
+        .filter(|variant| !variant.attrs.skip_deserializing())
+        .collect();
+
+    let tag = serde_attrs.tag();
+    let (untagged, tagged): (Vec<&Variant>, Vec<&Variant>) = variants
+        .into_iter()
+        .partition(|variant| matches!(tag, TagType::None) || variant.attrs.untagged());
+
+    let deny_unknown_fields = serde_attrs.deny_unknown_fields();
+    let check = |variant: &Variant, layout: Layout| {
+        let name = format!("{name}::{}", variant.ident);
+        expand_fields(
+            variant.style,
+            &variant.fields,
+            false,
+            deny_unknown_fields,
+            layout,
+            "variant",
+            &name,
+        )
+    };
+
+    let names: Vec<_> = tagged
+        .iter()
+        .map(|variant| variant.attrs.name().deserialize_name())
+        .collect();
+    let unknown = if tagged.iter().any(|variant| variant.attrs.other()) {
+        quote!(::core::result::Result::Ok(()))
+    } else {
+        quote!(::core::result::Result::Err(__v::unknown_variant(&variant, &[#(#names),*])))
+    };
+    let patterns: Vec<_> = tagged
+        .iter()
+        .map(|variant| {
+            let name = variant.attrs.name().deserialize_name();
+            let aliases = variant
+                .attrs
+                .aliases()
+                .iter()
+                .filter(|alias| *alias != name);
+            quote!(#name #(| #aliases)*)
+        })
+        .collect();
+
+    let tagged_body = match tag {
+        _ if tagged.is_empty() => None,
+        TagType::External => {
+            let expected = format!("enum {name}");
+            let checks = tagged.iter().map(|variant| check(variant, Layout::Direct));
+            Some(quote! {
+                let (variant, content) = __v::external_tag(js, #expected)?;
+                match variant.as_str() {
+                    #(#patterns => (|| -> ::core::result::Result<(), tsify::Mismatch> {
+                        let js = &content;
+                        #checks
+                    })().map_err(|m| m.at_key(&variant)),)*
+                    _ => #unknown,
+                }
+            })
+        }
+        TagType::Internal { tag } => {
+            let expected = format!("internally tagged enum {name}");
+            // The content is the rest of the same object, so a unit variant has nothing more.
+            let checks = tagged.iter().map(|variant| match variant.style {
+                Style::Unit => quote!(::core::result::Result::Ok(())),
+                _ => check(variant, Layout::Internal { tag }),
+            });
+            Some(quote! {
+                let object = __v::object(js, #expected)?;
+                let variant = __v::tag(object, #tag)?;
+                match variant.as_str() {
+                    #(#patterns => (|| -> ::core::result::Result<(), tsify::Mismatch> { #checks })(),)*
+                    _ => #unknown,
+                }
+            })
+        }
+        TagType::Adjacent { tag, content } => {
+            let expected = format!("adjacently tagged enum {name}");
+            let checks = tagged.iter().map(|variant| check(variant, Layout::Direct));
+            let deny_unknown_fields = deny_unknown_fields
+                .then(|| quote!(__v::deny_unknown_fields(object, &[#tag, #content], backend)?;));
+            Some(quote! {
+                let object = __v::object(js, #expected)?;
+                #deny_unknown_fields
+                let variant = __v::tag(object, #tag)?;
+                let content = __v::property(object, #content);
+                match variant.as_str() {
+                    #(#patterns => (|| -> ::core::result::Result<(), tsify::Mismatch> {
+                        let js = &content;
+                        #checks
+                    })().map_err(|m| m.at_key(#content)),)*
+                    _ => #unknown,
+                }
+            })
+        }
+        TagType::None => None,
+    };
+
+    if untagged.is_empty() {
+        return tagged_body.unwrap_or_else(|| quote!(::core::result::Result::Ok(())));
+    }
+
+    let tagged_check = tagged_body.map(|body| {
+        quote! {
+            let tagged: ::core::result::Result<(), tsify::Mismatch> = (|| -> ::core::result::Result<(), tsify::Mismatch> { #body })();
+            if tagged.is_ok() {
+                return tagged;
+            }
+        }
+    });
+    let untagged_checks = untagged
+        .iter()
+        .map(|variant| check(variant, Layout::Buffered));
+    let message = format!("data did not match any variant of untagged enum {name}");
+
+    quote! {
+        #tagged_check
+        #(
+            let result: ::core::result::Result<(), tsify::Mismatch> = (|| -> ::core::result::Result<(), tsify::Mismatch> { #untagged_checks })();
+            if result.is_ok() {
+                return result;
+            }
+        )*
+        ::core::result::Result::Err(tsify::Mismatch::custom(#message))
+    }
+}
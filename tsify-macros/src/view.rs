@@ -0,0 +1,223 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use serde_derive_internals::{
+    ast::{Data, Field},
+    attr,
+};
+use syn::ext::IdentExt;
+
+use crate::{
+    attrs::{custom_deserialize_with, View},
+    container::Container,
+    patch::expand_tsify_config,
+};
+
+/// A `#[tsify(view)]` type: a struct with the fields of the container that `fields` lists, or
+/// all but those `omit` lists, serialized and declared the way the container declares them, and
+/// `From<&Foo>` to fill it in.
+pub fn expand(cont: &Container, view: &View) -> TokenStream {
+    let name = match syn::parse_str::<syn::Ident>(&view.name) {
+        Ok(name) => name,
+        Err(_) => {
+            cont.syn_error(syn::Error::new(
+                view.span,
+                format!(
+                    "#[tsify(view(name = \"{}\"))] is not a valid type name",
+                    view.name
+                ),
+            ));
+            return TokenStream::new();
+        }
+    };
+
+    let Data::Struct(_, fields) = cont.serde_data() else {
+        return TokenStream::new();
+    };
+
+    let ident = cont.ident();
+    for field in &view.fields {
+        if !fields.iter().any(|f| field_name(f) == *field) {
+            cont.syn_error(syn::Error::new(
+                view.span,
+                format!("#[tsify(view)] names `{field}`, which is not a field of `{ident}`"),
+            ));
+        }
+    }
+
+    // The fields keep the order of the container, as in its declaration.
+    let fields: Vec<_> = fields
+        .iter()
+        .filter(|field| view.fields.contains(&field_name(field)) != view.omit)
+        .collect();
+
+    let vis = &cont.serde_container.original.vis;
+    let serde_attrs = cont.serde_attrs();
+
+    let serde_path = match serde_attrs.custom_serde_path() {
+        Some(path) => path.to_token_stream(),
+        None => quote!(serde),
+    };
+    let serde_crate = serde_attrs.custom_serde_path().map(|path| {
+        let path = path.to_token_stream().to_string().replace(' ', "");
+        quote!(#[serde(crate = #path)])
+    });
+    let deny_unknown_fields = serde_attrs
+        .deny_unknown_fields()
+        .then(|| quote!(#[serde(deny_unknown_fields)]));
+
+    // The doc names the fields as they are declared.
+    let list = fields
+        .iter()
+        .map(|field| format!("`{}`", field.attrs.name().serialize_name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!(" A view of `{ident}` with only {list}.");
+    let tsify_config = expand_tsify_config(cont);
+    let view_fields = fields.iter().map(|field| expand_field(cont, &name, field));
+    let defaults: Vec<_> = fields
+        .iter()
+        .filter_map(|field| expand_container_default(cont, field))
+        .collect();
+    let defaults = (!defaults.is_empty()).then(|| {
+        quote! {
+            #[automatically_derived]
+            #[allow(deprecated)]
+            impl #name {
+                #(#defaults)*
+            }
+        }
+    });
+    let members: Vec<_> = fields.iter().map(|field| &field.member).collect();
+
+    quote! {
+        #[doc = #doc]
+        #[derive(#serde_path::Serialize, #serde_path::Deserialize, tsify::Tsify)]
+        #serde_crate
+        #deny_unknown_fields
+        #tsify_config
+        #vis struct #name {
+            #(#view_fields)*
+        }
+
+        #defaults
+
+        #[automatically_derived]
+        #[allow(deprecated)]
+        impl ::core::convert::From<&#ident> for #name {
+            fn from(value: &#ident) -> Self {
+                Self {
+                    #(#members: ::core::clone::Clone::clone(&value.#members),)*
+                }
+            }
+        }
+    }
+}
+
+/// The name of `field` in Rust, which is how a view lists it.
+fn field_name(field: &Field) -> String {
+    match &field.original.ident {
+        Some(ident) => ident.unraw().to_string(),
+        None => String::new(),
+    }
+}
+
+/// Copy a field into the view. The serde attributes are written out as serde resolved them, so
+/// that names from `rename_all` and defaults from the container carry over without the
+/// container's attributes.
+fn expand_field(cont: &Container, view: &syn::Ident, field: &Field) -> TokenStream {
+    let original = field.original;
+    let attrs = original.attrs.iter().filter(|attr| {
+        let path = attr.path();
+        path.is_ident("doc") || path.is_ident("deprecated") || path.is_ident("tsify")
+    });
+    let vis = &original.vis;
+    let member = &field.member;
+    let ty = field.ty;
+
+    let serialize = field.attrs.name().serialize_name();
+    let deserialize = field.attrs.name().deserialize_name();
+    let mut options = vec![quote!(rename(serialize = #serialize, deserialize = #deserialize))];
+
+    options.extend(
+        field
+            .attrs
+            .aliases()
+            .iter()
+            .filter(|alias| *alias != deserialize)
+            .map(|alias| quote!(alias = #alias)),
+    );
+
+    match field.attrs.default() {
+        attr::Default::Path(path) => {
+            let path = path_str(path);
+            options.push(quote!(default = #path));
+        }
+        attr::Default::Default => options.push(quote!(default)),
+        attr::Default::None if !cont.serde_attrs().default().is_none() => {
+            let path = format!("{view}::{}", default_ident(field));
+            options.push(quote!(default = #path));
+        }
+        attr::Default::None => {}
+    }
+
+    if field.attrs.flatten() {
+        options.push(quote!(flatten));
+    }
+    if field.attrs.skip_serializing() {
+        options.push(quote!(skip_serializing));
+    }
+    if field.attrs.skip_deserializing() {
+        options.push(quote!(skip_deserializing));
+    }
+    if let Some(path) = field.attrs.skip_serializing_if() {
+        let path = path_str(path);
+        options.push(quote!(skip_serializing_if = #path));
+    }
+    if let Some(path) = field.attrs.serialize_with() {
+        let path = path_str(path);
+        options.push(quote!(serialize_with = #path));
+    }
+    if let Some(path) = custom_deserialize_with(field) {
+        let path = path_str(path);
+        options.push(quote!(deserialize_with = #path));
+    }
+
+    quote! {
+        #(#attrs)*
+        #[serde(#(#options),*)]
+        #vis #member: #ty,
+    }
+}
+
+/// For a field that takes its default from the container's, a function that picks it out of the
+/// container's default, for the field's `default = "..."`.
+fn expand_container_default(cont: &Container, field: &Field) -> Option<TokenStream> {
+    if !field.attrs.default().is_none() {
+        return None;
+    }
+
+    let ident = cont.ident();
+    let container = match cont.serde_attrs().default() {
+        attr::Default::None => return None,
+        attr::Default::Default => quote!(<#ident as ::core::default::Default>::default()),
+        attr::Default::Path(path) => quote!(#path()),
+    };
+    let default = default_ident(field);
+    let member = &field.member;
+    let ty = field.ty;
+
+    Some(quote! {
+        #[doc(hidden)]
+        fn #default() -> #ty {
+            #container.#member
+        }
+    })
+}
+
+fn default_ident(field: &Field) -> syn::Ident {
+    format_ident!("__tsify_default_{}", field_name(field))
+}
+
+fn path_str(path: &syn::ExprPath) -> String {
+    path.to_token_stream().to_string().replace(' ', "")
+}
@@ -1,21 +1,8 @@
-use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::quote;
-use quote::quote_spanned;
 use syn::parse_quote;
 
-use crate::{container::Container, decl::Decl};
-
-fn mark_deprecated(span: Span, note: &str) -> TokenStream {
-    quote_spanned!(span =>
-        #[allow(non_upper_case_globals)]
-        const _: () = {
-            #[deprecated(note = #note)]
-            const _x: () = ();
-            _x
-        };
-    )
-}
+use crate::{attrs::Backend, container::Container, decl::Decl, error_tracker::mark_deprecated};
 
 pub fn expand(cont: &Container, decl: Decl) -> TokenStream {
     let attrs = &cont.attrs;
@@ -70,10 +57,18 @@ pub fn expand(cont: &Container, decl: Decl) -> TokenStream {
         });
 
     let typescript_type = decl.id();
+    // wasm-bindgen's descriptor interpreter cannot run a loop over a string, so spell it out.
+    let typescript_type_len = typescript_type.chars().count() as u32;
+    let typescript_type_chars = typescript_type.chars().map(|c| c as u32);
 
+    let backend = match attrs.ty_config.backend {
+        Backend::Json => quote!(Json),
+        Backend::Js => quote!(Js),
+    };
     let missing_as_null = attrs.ty_config.missing_as_null;
     let hashmap_as_object = attrs.ty_config.hashmap_as_object;
     let large_number_types_as_bigints = attrs.ty_config.large_number_types_as_bigints;
+    let bytes_as_arrays = attrs.ty_config.bytes_as_arrays;
 
     quote! {
         const _: () = {
@@ -98,10 +93,17 @@ pub fn expand(cont: &Container, decl: Decl) -> TokenStream {
                 type JsType = JsType;
                 const DECL: &'static str = #decl_str;
                 const SERIALIZATION_CONFIG: tsify::SerializationConfig = tsify::SerializationConfig {
+                    backend: tsify::Backend::#backend,
                     missing_as_null: #missing_as_null,
                     hashmap_as_object: #hashmap_as_object,
                     large_number_types_as_bigints: #large_number_types_as_bigints,
+                    bytes_as_arrays: #bytes_as_arrays,
                 };
+                const TYPESCRIPT_TYPE_LEN: u32 = #typescript_type_len;
+
+                fn describe_typescript_type() {
+                    #(tsify::describe::inform(#typescript_type_chars);)*
+                }
             }
 
             #typescript_custom_section